@@ -0,0 +1,203 @@
+use std::fmt::Write;
+
+use super::indexed::Indexed;
+use super::lhlist;
+
+
+// --------==========[ Type Names ]==========--------
+
+/// Names of the type in shading languages
+pub trait TypeName {
+    const GLSL: &'static str;
+    const WGSL: &'static str;
+}
+
+macro_rules! impl_type_name {
+    ($($ty:ty => ($glsl:literal, $wgsl:literal)),* $(,)?) => {
+        $(
+            impl TypeName for $ty {
+                const GLSL: &'static str = $glsl;
+                const WGSL: &'static str = $wgsl;
+            }
+        )*
+    };
+}
+
+impl_type_name!(
+    // WGSL `bool` is not host-shareable, booleans are written as `u32`
+    bool => ("bool", "u32"),
+    i32 => ("int", "i32"),
+    u32 => ("uint", "u32"),
+    f32 => ("float", "f32"),
+    [bool; 2] => ("bvec2", "vec2<u32>"),
+    [bool; 3] => ("bvec3", "vec3<u32>"),
+    [bool; 4] => ("bvec4", "vec4<u32>"),
+    [i32; 2] => ("ivec2", "vec2<i32>"),
+    [i32; 3] => ("ivec3", "vec3<i32>"),
+    [i32; 4] => ("ivec4", "vec4<i32>"),
+    [u32; 2] => ("uvec2", "vec2<u32>"),
+    [u32; 3] => ("uvec3", "vec3<u32>"),
+    [u32; 4] => ("uvec4", "vec4<u32>"),
+    [f32; 2] => ("vec2", "vec2<f32>"),
+    [f32; 3] => ("vec3", "vec3<f32>"),
+    [f32; 4] => ("vec4", "vec4<f32>"),
+    [[f32; 2]; 2] => ("mat2", "mat2x2<f32>"),
+    [[f32; 3]; 2] => ("mat2x3", "mat2x3<f32>"),
+    [[f32; 4]; 2] => ("mat2x4", "mat2x4<f32>"),
+    [[f32; 2]; 3] => ("mat3x2", "mat3x2<f32>"),
+    [[f32; 3]; 3] => ("mat3", "mat3x3<f32>"),
+    [[f32; 4]; 3] => ("mat3x4", "mat3x4<f32>"),
+    [[f32; 2]; 4] => ("mat4x2", "mat4x2<f32>"),
+    [[f32; 3]; 4] => ("mat4x3", "mat4x3<f32>"),
+    [[f32; 4]; 4] => ("mat4", "mat4x4<f32>"),
+);
+
+
+// --------==========[ Labels ]==========--------
+
+/// Value together with its name in shader source.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Labelled<T> {
+    pub name: &'static str,
+    pub value: T,
+}
+
+impl<T> Labelled<T> {
+    pub fn new(name: &'static str, value: T) -> Self {
+        Self { name, value }
+    }
+}
+
+
+// --------==========[ Declarations ]==========--------
+
+/// Single uniform declaration
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Declaration<'a> {
+    pub name: &'a str,
+    pub glsl: &'static str,
+    pub wgsl: &'static str,
+    pub location: Option<usize>,
+}
+
+impl Declaration<'_> {
+    pub fn glsl(&self) -> String {
+        match self.location {
+            Some(location) => format!("layout(location = {location}) uniform {} {};", self.glsl, self.name),
+            None => format!("uniform {} {};", self.glsl, self.name),
+        }
+    }
+
+    pub fn wgsl(&self) -> String {
+        format!("{}: {},", self.name, self.wgsl)
+    }
+}
+
+/// Element which can be declared in shader source
+pub trait Declare {
+    fn declaration(&self) -> Declaration<'_>;
+}
+
+impl<T: TypeName> Declare for Labelled<T> {
+    fn declaration(&self) -> Declaration<'_> {
+        Declaration { name: self.name, glsl: T::GLSL, wgsl: T::WGSL, location: None }
+    }
+}
+
+impl<const INDEX: usize, T: Declare> Declare for Indexed<INDEX, T> {
+    fn declaration(&self) -> Declaration<'_> {
+        Declaration { location: Some(INDEX), ..self.get().declaration() }
+    }
+}
+
+
+// --------==========[ Source Generation ]==========--------
+
+/// LHList of labelled uniforms, optionally `Indexed` by their location
+pub trait Declarations: lhlist::Base {
+    fn collect_declarations<'a>(&'a self, declarations: &mut Vec<Declaration<'a>>);
+
+    fn declarations(&self) -> Vec<Declaration<'_>> {
+        let mut declarations = Vec::with_capacity(Self::LENGTH);
+        self.collect_declarations(&mut declarations);
+        declarations
+    }
+
+    /// GLSL `uniform` declarations, one per line
+    fn glsl(&self) -> String {
+        let mut source = String::new();
+        for declaration in self.declarations() {
+            writeln!(source, "{}", declaration.glsl()).unwrap();
+        }
+        source
+    }
+
+    /// WGSL struct named `name` with one member per uniform
+    fn wgsl(&self, name: &str) -> String {
+        let mut source = format!("struct {name} {{\n");
+        for declaration in self.declarations() {
+            writeln!(source, "    {}", declaration.wgsl()).unwrap();
+        }
+        source.push_str("}\n");
+        source
+    }
+}
+
+/// Base case
+impl Declarations for () {
+    fn collect_declarations<'a>(&'a self, _: &mut Vec<Declaration<'a>>) { }
+}
+
+/// Inductive step
+impl<H: Declarations, E: Declare> Declarations for (H, E) {
+    fn collect_declarations<'a>(&'a self, declarations: &mut Vec<Declaration<'a>>) {
+        self.0.collect_declarations(declarations);
+        declarations.push(self.1.declaration());
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uniforms() -> impl Declarations {
+        (
+            (((), Indexed::<0, _>::new(Labelled::new("time", 0.0f32))), Labelled::new("color", [0.0f32; 3])),
+            Indexed::<3, _>::new(Labelled::new("mvp", [[0.0f32; 3]; 4])),
+        )
+    }
+
+    #[test]
+    fn glsl_declarations() {
+        assert_eq!(uniforms().glsl(), "\
+layout(location = 0) uniform float time;
+uniform vec3 color;
+layout(location = 3) uniform mat4x3 mvp;
+");
+    }
+
+    #[test]
+    fn wgsl_struct() {
+        assert_eq!(uniforms().wgsl("Uniforms"), "\
+struct Uniforms {
+    time: f32,
+    color: vec3<f32>,
+    mvp: mat4x3<f32>,
+}
+");
+    }
+
+    #[test]
+    fn booleans() {
+        let uniforms = (((), Labelled::new("enabled", true)), Labelled::new("mask", [false; 3]));
+        assert_eq!(uniforms.glsl(), "uniform bool enabled;\nuniform bvec3 mask;\n");
+        assert_eq!(uniforms.wgsl("Flags"), "struct Flags {\n    enabled: u32,\n    mask: vec3<u32>,\n}\n");
+    }
+
+    #[test]
+    fn empty_list() {
+        assert_eq!(().glsl(), "");
+        assert_eq!(().wgsl("Empty"), "struct Empty {\n}\n");
+    }
+}
@@ -0,0 +1,324 @@
+use super::counters;
+
+
+// --------==========[ Coproduct ]==========--------
+
+/// Type level sum, right folded like RHList.
+///
+/// `Coproduct<A, Coproduct<B, Nil>>` holds either an `A` or a `B`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Coproduct<H, T> {
+    Inl(H),
+    Inr(T),
+}
+
+/// Empty Coproduct, cannot be constructed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Nil { }
+
+pub use Coproduct::{Inl, Inr};
+
+
+// --------==========[ Base Traits ]==========--------
+
+pub trait Base: Sized {
+    const LENGTH: usize;
+}
+
+impl Base for Nil {
+    const LENGTH: usize = 0;
+}
+
+impl<H, T: Base> Base for Coproduct<H, T> {
+    const LENGTH: usize = T::LENGTH + 1;
+}
+
+
+// --------==========[ Injection ]==========--------
+
+/// Construction of Coproduct from one of its variants
+pub trait Injector<Needle, I>: Base
+where
+    I: counters::Index
+{
+    fn inject(value: Needle) -> Self;
+}
+
+impl<Needle, T: Base> Injector<Needle, counters::Zero> for Coproduct<Needle, T> {
+    fn inject(value: Needle) -> Self {
+        Inl(value)
+    }
+}
+
+impl<H, T, Needle, I> Injector<Needle, counters::Successor<I>> for Coproduct<H, T>
+where
+    T: Injector<Needle, I>,
+    I: counters::Index,
+{
+    fn inject(value: Needle) -> Self {
+        Inr(T::inject(value))
+    }
+}
+
+
+// --------==========[ Coproduct Selectors ]==========--------
+
+/// Borrowing access to a variant
+pub trait Selector<Needle, I>: Base
+where
+    I: counters::Index
+{
+    fn get(&self) -> Option<&Needle>;
+
+    fn get_mut(&mut self) -> Option<&mut Needle>;
+}
+
+impl<Needle, T: Base> Selector<Needle, counters::Zero> for Coproduct<Needle, T> {
+    fn get(&self) -> Option<&Needle> {
+        match self {
+            Inl(value) => Some(value),
+            Inr(_) => None,
+        }
+    }
+
+    fn get_mut(&mut self) -> Option<&mut Needle> {
+        match self {
+            Inl(value) => Some(value),
+            Inr(_) => None,
+        }
+    }
+}
+
+impl<H, T, Needle, I> Selector<Needle, counters::Successor<I>> for Coproduct<H, T>
+where
+    T: Selector<Needle, I>,
+    I: counters::Index,
+{
+    fn get(&self) -> Option<&Needle> {
+        match self {
+            Inl(_) => None,
+            Inr(tail) => tail.get(),
+        }
+    }
+
+    fn get_mut(&mut self) -> Option<&mut Needle> {
+        match self {
+            Inl(_) => None,
+            Inr(tail) => tail.get_mut(),
+        }
+    }
+}
+
+
+// --------==========[ Coproduct Taker ]==========--------
+
+/// Extraction of a variant, yielding Coproduct of remaining variants on failure
+pub trait Taker<Needle, I>: Base
+where
+    I: counters::Index
+{
+    type Remainder;
+
+    fn take(self) -> Result<Needle, Self::Remainder>;
+}
+
+/// Base case
+impl<Needle, T: Base> Taker<Needle, counters::Zero> for Coproduct<Needle, T> {
+    type Remainder = T;
+
+    fn take(self) -> Result<Needle, Self::Remainder> {
+        match self {
+            Inl(value) => Ok(value),
+            Inr(tail) => Err(tail),
+        }
+    }
+}
+
+/// Inductive step
+impl<H, T, Needle, I> Taker<Needle, counters::Successor<I>> for Coproduct<H, T>
+where
+    T: Taker<Needle, I>,
+    I: counters::Index,
+{
+    type Remainder = Coproduct<H, T::Remainder>;
+
+    fn take(self) -> Result<Needle, Self::Remainder> {
+        match self {
+            Inl(value) => Err(Inl(value)),
+            Inr(tail) => tail.take().map_err(Inr),
+        }
+    }
+}
+
+
+// --------==========[ Positional Access ]==========--------
+
+/// Access to variant by explicit index, counted the same way as in `Selector`
+///
+/// Index may be unary or binary counter, see [`counters::Decrement`].
+pub trait At<I: counters::Index>: Base {
+    type Output;
+    type Remainder;
+
+    fn inject_at(value: Self::Output) -> Self;
+
+    fn at(&self) -> Option<&Self::Output>;
+
+    fn at_mut(&mut self) -> Option<&mut Self::Output>;
+
+    fn take_at(self) -> Result<Self::Output, Self::Remainder>;
+}
+
+/// Base case
+impl<H, T: Base> At<counters::Zero> for Coproduct<H, T> {
+    type Output = H;
+    type Remainder = T;
+
+    fn inject_at(value: Self::Output) -> Self {
+        Inl(value)
+    }
+
+    fn at(&self) -> Option<&Self::Output> {
+        match self {
+            Inl(value) => Some(value),
+            Inr(_) => None,
+        }
+    }
+
+    fn at_mut(&mut self) -> Option<&mut Self::Output> {
+        match self {
+            Inl(value) => Some(value),
+            Inr(_) => None,
+        }
+    }
+
+    fn take_at(self) -> Result<Self::Output, Self::Remainder> {
+        match self {
+            Inl(value) => Ok(value),
+            Inr(tail) => Err(tail),
+        }
+    }
+}
+
+/// Inductive step
+impl<H, T, I> At<I> for Coproduct<H, T>
+where
+    I: counters::Decrement,
+    T: At<I::Output>,
+{
+    type Output = T::Output;
+    type Remainder = Coproduct<H, T::Remainder>;
+
+    fn inject_at(value: Self::Output) -> Self {
+        Inr(T::inject_at(value))
+    }
+
+    fn at(&self) -> Option<&Self::Output> {
+        match self {
+            Inl(_) => None,
+            Inr(tail) => tail.at(),
+        }
+    }
+
+    fn at_mut(&mut self) -> Option<&mut Self::Output> {
+        match self {
+            Inl(_) => None,
+            Inr(tail) => tail.at_mut(),
+        }
+    }
+
+    fn take_at(self) -> Result<Self::Output, Self::Remainder> {
+        match self {
+            Inl(value) => Err(Inl(value)),
+            Inr(tail) => tail.take_at().map_err(Inr),
+        }
+    }
+}
+
+
+// --------==========[ Inherent API ]==========--------
+
+impl<H, T: Base> Coproduct<H, T> {
+    /// Construct Coproduct from value, variant is located by its type.
+    pub fn inject<Needle, I>(value: Needle) -> Self
+    where
+        Self: Injector<Needle, I>,
+        I: counters::Index,
+    {
+        <Self as Injector<Needle, I>>::inject(value)
+    }
+
+    pub fn get<Needle, I>(&self) -> Option<&Needle>
+    where
+        Self: Selector<Needle, I>,
+        I: counters::Index,
+    {
+        <Self as Selector<Needle, I>>::get(self)
+    }
+
+    pub fn get_mut<Needle, I>(&mut self) -> Option<&mut Needle>
+    where
+        Self: Selector<Needle, I>,
+        I: counters::Index,
+    {
+        <Self as Selector<Needle, I>>::get_mut(self)
+    }
+
+    pub fn take<Needle, I>(self) -> Result<Needle, <Self as Taker<Needle, I>>::Remainder>
+    where
+        Self: Taker<Needle, I>,
+        I: counters::Index,
+    {
+        <Self as Taker<Needle, I>>::take(self)
+    }
+}
+
+impl Nil {
+    /// Empty Coproduct has no values so any type can be produced from it.
+    pub fn absurd<T>(self) -> T {
+        match self { }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Event = Coproduct<u8, Coproduct<&'static str, Coproduct<f32, Nil>>>;
+
+    #[test]
+    fn inject_get_take() {
+        let mut event = Event::inject(7u8);
+        assert_eq!(event, Inl(7));
+        assert_eq!(event.get::<u8, _>(), Some(&7));
+        assert_eq!(event.get::<&str, _>(), None);
+        *event.get_mut::<u8, _>().unwrap() += 1;
+        assert_eq!(event.take::<u8, _>(), Ok(8));
+
+        let event = Event::inject("name");
+        assert_eq!(event.get::<&str, _>(), Some(&"name"));
+        assert_eq!(event.clone().take::<f32, _>(), Err(Coproduct::inject("name")));
+        assert_eq!(event.take::<&str, _>(), Ok("name"));
+
+        let event = Event::inject(0.5f32);
+        assert_eq!(event, Inr(Inr(Inl(0.5))));
+        assert_eq!(event.get::<u8, _>(), None);
+        let remainder: Coproduct<&str, Coproduct<f32, Nil>> = event.take::<u8, _>().unwrap_err();
+        assert_eq!(remainder.take::<f32, _>(), Ok(0.5));
+    }
+
+    #[test]
+    fn positional_access() {
+        type Two = counters::B0<counters::B1<counters::Zero>>;
+
+        let mut event = <Event as At<counters::Counter<1>>>::inject_at("name");
+        assert_eq!(At::<counters::Zero>::at(&event), None);
+        *At::<counters::Counter<1>>::at_mut(&mut event).unwrap() = "other";
+        assert_eq!(At::<counters::Counter<1>>::take_at(event), Ok("other"));
+
+        let event = <Event as At<Two>>::inject_at(0.5);
+        assert_eq!(At::<Two>::at(&event), Some(&0.5));
+        assert_eq!(At::<counters::Zero>::take_at(event), Err(Inr(Inl(0.5))));
+    }
+}
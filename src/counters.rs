@@ -16,3 +16,789 @@ pub struct Successor<I: Index>(PhantomData<I>);
 impl<I: Index> Index for Successor<I> {
     const INDEX: usize = I::INDEX + 1;
 }
+
+
+// --------==========[ Const Generic Bridge ]==========--------
+
+/// Marker carrying const generic `usize` as a type
+pub struct Const<const N: usize>;
+
+/// Mapping from `Const<N>` to counter with the same `INDEX`
+pub trait ToCounter {
+    type Counter: Index;
+}
+
+/// Mapping from counter to `Const<N>` with `N == INDEX`
+pub trait ToConst: Index {
+    type Const;
+}
+
+/// Counter for const generic `N`
+pub type Counter<const N: usize> = <Const<N> as ToCounter>::Counter;
+
+macro_rules! impl_const_bridge {
+    ($counter:ty;) => { };
+    ($counter:ty; $a:literal $b:literal $c:literal $d:literal $($rest:literal)+) => {
+        impl_const_bridge!($counter; $a $b $c $d);
+        impl_const_bridge!(Successor<Successor<Successor<Successor<$counter>>>>; $($rest)*);
+    };
+    ($counter:ty; $n:literal $($rest:literal)*) => {
+        impl ToCounter for Const<$n> {
+            type Counter = $counter;
+        }
+
+        impl ToConst for $counter {
+            type Const = Const<$n>;
+        }
+
+        impl_const_bridge!(Successor<$counter>; $($rest)*);
+    };
+}
+
+// Deeper unary counters overflow default recursion limit.
+impl_const_bridge!(Zero;
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15
+    16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+    32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+    48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+    64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79
+    80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+    96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+    112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
+);
+
+
+// --------==========[ Type Level Arithmetic ]==========--------
+
+/// Sum of counters
+pub trait Add<Rhs: Index>: Index {
+    type Output: Index;
+}
+
+/// Base case
+impl<Rhs: Index> Add<Rhs> for Zero {
+    type Output = Rhs;
+}
+
+/// Inductive step
+impl<I: Add<Rhs>, Rhs: Index> Add<Rhs> for Successor<I> {
+    type Output = Successor<I::Output>;
+}
+
+/// Difference of counters, defined only for `Rhs` not greater than `Self`
+pub trait Sub<Rhs: Index>: Index {
+    type Output: Index;
+}
+
+/// Base case
+impl<I: Index> Sub<Zero> for I {
+    type Output = I;
+}
+
+/// Inductive step
+impl<I: Sub<Rhs>, Rhs: Index> Sub<Successor<Rhs>> for Successor<I> {
+    type Output = I::Output;
+}
+
+/// Product of counters
+pub trait Mul<Rhs: Index>: Index {
+    type Output: Index;
+}
+
+/// Base case
+impl<Rhs: Index> Mul<Rhs> for Zero {
+    type Output = Zero;
+}
+
+/// Inductive step
+impl<I, Rhs> Mul<Rhs> for Successor<I>
+where
+    I: Mul<Rhs>,
+    Rhs: Add<I::Output>,
+{
+    type Output = <Rhs as Add<I::Output>>::Output;
+}
+
+/// Smaller of two counters
+pub trait Min<Rhs: Index>: Index {
+    type Output: Index;
+}
+
+impl<I: Index> Min<Zero> for I {
+    type Output = Zero;
+}
+
+impl<Rhs: Index> Min<Successor<Rhs>> for Zero {
+    type Output = Zero;
+}
+
+impl<I: Min<Rhs>, Rhs: Index> Min<Successor<Rhs>> for Successor<I> {
+    type Output = Successor<I::Output>;
+}
+
+/// Greater of two counters
+pub trait Max<Rhs: Index>: Index {
+    type Output: Index;
+}
+
+impl<I: Index> Max<Zero> for I {
+    type Output = I;
+}
+
+impl<Rhs: Index> Max<Successor<Rhs>> for Zero {
+    type Output = Successor<Rhs>;
+}
+
+impl<I: Max<Rhs>, Rhs: Index> Max<Successor<Rhs>> for Successor<I> {
+    type Output = Successor<I::Output>;
+}
+
+pub type Sum<L, R> = <L as Add<R>>::Output;
+pub type Diff<L, R> = <L as Sub<R>>::Output;
+pub type Prod<L, R> = <L as Mul<R>>::Output;
+pub type Minimum<L, R> = <L as Min<R>>::Output;
+pub type Maximum<L, R> = <L as Max<R>>::Output;
+
+
+// --------==========[ Type Level Booleans ]==========--------
+
+pub trait Bool {
+    const VALUE: bool;
+
+    /// `A` for `True`, `B` for `False`
+    type If<A, B>;
+}
+
+pub struct True;
+impl Bool for True {
+    const VALUE: bool = true;
+    type If<A, B> = A;
+}
+
+pub struct False;
+impl Bool for False {
+    const VALUE: bool = false;
+    type If<A, B> = B;
+}
+
+/// Type level conditional
+pub type If<C, A, B> = <C as Bool>::If<A, B>;
+
+/// Disjunction of booleans
+pub trait Or<Rhs: Bool>: Bool {
+    type Output: Bool;
+}
+
+impl<Rhs: Bool> Or<Rhs> for True {
+    type Output = True;
+}
+
+impl<Rhs: Bool> Or<Rhs> for False {
+    type Output = Rhs;
+}
+
+
+// --------==========[ Type Level Comparison ]==========--------
+
+/// Equality of counters
+pub trait Eq<Rhs: Index>: Index {
+    type Output: Bool;
+}
+
+impl Eq<Zero> for Zero {
+    type Output = True;
+}
+
+impl<Rhs: Index> Eq<Successor<Rhs>> for Zero {
+    type Output = False;
+}
+
+impl<I: Index> Eq<Zero> for Successor<I> {
+    type Output = False;
+}
+
+impl<I: Eq<Rhs>, Rhs: Index> Eq<Successor<Rhs>> for Successor<I> {
+    type Output = I::Output;
+}
+
+/// Strict ordering of counters
+///
+/// Index `I` within bounds of list `L` can be required with `I: Lt<L::Length, Output = True>`.
+pub trait Lt<Rhs: Index>: Index {
+    type Output: Bool;
+}
+
+impl Lt<Zero> for Zero {
+    type Output = False;
+}
+
+impl<Rhs: Index> Lt<Successor<Rhs>> for Zero {
+    type Output = True;
+}
+
+impl<I: Index> Lt<Zero> for Successor<I> {
+    type Output = False;
+}
+
+impl<I: Lt<Rhs>, Rhs: Index> Lt<Successor<Rhs>> for Successor<I> {
+    type Output = I::Output;
+}
+
+/// Non strict ordering of counters
+pub trait Le<Rhs: Index>: Index {
+    type Output: Bool;
+}
+
+impl<Rhs: Index> Le<Rhs> for Zero {
+    type Output = True;
+}
+
+impl<I: Index> Le<Zero> for Successor<I> {
+    type Output = False;
+}
+
+impl<I: Le<Rhs>, Rhs: Index> Le<Successor<Rhs>> for Successor<I> {
+    type Output = I::Output;
+}
+
+
+// --------==========[ Binary Counters ]==========--------
+
+/// Binary counter equal to `2 * N`, least significant bit is outermost.
+///
+/// `Zero` terminates binary counters, `B0<Zero>` is not a valid counter.
+/// Traits accepting them are listed on [`Decrement`].
+pub struct B0<N: Index>(PhantomData<N>);
+impl<N: Index> Index for B0<N> {
+    const INDEX: usize = 2 * N::INDEX;
+}
+
+/// Binary counter equal to `2 * N + 1`
+pub struct B1<N: Index>(PhantomData<N>);
+impl<N: Index> Index for B1<N> {
+    const INDEX: usize = 2 * N::INDEX + 1;
+}
+
+/// Counter smaller by one, used by index taking traits to walk binary counters
+///
+/// Traits taking explicit index (`At` of lists and `Coproduct`) walk it
+/// with `Decrement`, so they accept both unary and binary counters,
+/// binary ones keep large indices cheap to compile. Arithmetic, comparison
+/// and `indexed` traits comparing const indices accept binary counters too.
+///
+/// Traits with inferred index (`Selector`, `Remover`, `Injector`, ...)
+/// stay unary only, second counter kind would make the inference ambiguous.
+pub trait Decrement: Index {
+    type Output: Index;
+}
+
+impl<I: Index> Decrement for Successor<I> {
+    type Output = I;
+}
+
+impl Decrement for B1<Zero> {
+    type Output = Zero;
+}
+
+impl<N: Index> Decrement for B1<B0<N>> {
+    type Output = B0<B0<N>>;
+}
+
+impl<N: Index> Decrement for B1<B1<N>> {
+    type Output = B0<B1<N>>;
+}
+
+impl<N: Decrement> Decrement for B0<N> {
+    type Output = B1<N::Output>;
+}
+
+/// Binary counter greater by one
+pub trait Increment: Index {
+    type Output: Index;
+}
+
+impl Increment for Zero {
+    type Output = B1<Zero>;
+}
+
+impl<N: Index> Increment for B0<N> {
+    type Output = B1<N>;
+}
+
+impl<N: Increment> Increment for B1<N> {
+    type Output = B0<N::Output>;
+}
+
+/// Conversion to binary counter
+///
+/// Unary counters convert at any size, `Const<N>` only within the limit of [`Binary`].
+pub trait IntoBinary {
+    type Binary: Index;
+}
+
+impl IntoBinary for Zero {
+    type Binary = Zero;
+}
+
+impl<I: Index + IntoBinary> IntoBinary for Successor<I>
+where
+    I::Binary: Increment,
+{
+    type Binary = <I::Binary as Increment>::Output;
+}
+
+impl<N: Index> IntoBinary for B0<N> {
+    type Binary = Self;
+}
+
+impl<N: Index> IntoBinary for B1<N> {
+    type Binary = Self;
+}
+
+/// Conversion to unary counter
+pub trait IntoUnary: Index {
+    type Unary: Index;
+}
+
+impl IntoUnary for Zero {
+    type Unary = Zero;
+}
+
+impl<I: Index> IntoUnary for Successor<I> {
+    type Unary = Self;
+}
+
+impl<N> IntoUnary for B0<N>
+where
+    N: IntoUnary,
+    N::Unary: Add<N::Unary>,
+{
+    type Unary = Sum<N::Unary, N::Unary>;
+}
+
+impl<N> IntoUnary for B1<N>
+where
+    N: IntoUnary,
+    N::Unary: Add<N::Unary>,
+{
+    type Unary = Successor<Sum<N::Unary, N::Unary>>;
+}
+
+/// Binary counter for const generic `N`
+///
+/// Defined only for `N` below 1024. Traits which compare or compute indices
+/// as binary counters (`indexed` `Lacks`, `HasIndex`, `Insert`, `Shift`, ...)
+/// therefore accept indices below 1024 only.
+pub type Binary<const N: usize> = <Const<N> as IntoBinary>::Binary;
+
+impl IntoBinary for Const<0> {
+    type Binary = Zero;
+}
+
+macro_rules! impl_binary_bridge {
+    ([$($counter:ty),*]) => { };
+    ([$($counter:ty),*] [$($n:literal)*] $($rest:tt)*) => {
+        $(
+            impl IntoBinary for Const<$n> {
+                type Binary = $counter;
+            }
+
+            impl ToConst for $counter {
+                type Const = Const<$n>;
+            }
+        )*
+
+        impl_binary_bridge!([$(B0<$counter>, B1<$counter>),*] $($rest)*);
+    };
+}
+
+// Every binary counter below 1024, grouped by bit length.
+impl_binary_bridge!([B1<Zero>]
+    [1]
+    [2 3]
+    [4 5 6 7]
+    [8 9 10 11 12 13 14 15]
+    [16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31]
+    [
+        32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+        48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+    ]
+    [
+        64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79
+        80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+        96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111
+        112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127
+    ]
+    [
+        128 129 130 131 132 133 134 135 136 137 138 139 140 141 142 143
+        144 145 146 147 148 149 150 151 152 153 154 155 156 157 158 159
+        160 161 162 163 164 165 166 167 168 169 170 171 172 173 174 175
+        176 177 178 179 180 181 182 183 184 185 186 187 188 189 190 191
+        192 193 194 195 196 197 198 199 200 201 202 203 204 205 206 207
+        208 209 210 211 212 213 214 215 216 217 218 219 220 221 222 223
+        224 225 226 227 228 229 230 231 232 233 234 235 236 237 238 239
+        240 241 242 243 244 245 246 247 248 249 250 251 252 253 254 255
+    ]
+    [
+        256 257 258 259 260 261 262 263 264 265 266 267 268 269 270 271
+        272 273 274 275 276 277 278 279 280 281 282 283 284 285 286 287
+        288 289 290 291 292 293 294 295 296 297 298 299 300 301 302 303
+        304 305 306 307 308 309 310 311 312 313 314 315 316 317 318 319
+        320 321 322 323 324 325 326 327 328 329 330 331 332 333 334 335
+        336 337 338 339 340 341 342 343 344 345 346 347 348 349 350 351
+        352 353 354 355 356 357 358 359 360 361 362 363 364 365 366 367
+        368 369 370 371 372 373 374 375 376 377 378 379 380 381 382 383
+        384 385 386 387 388 389 390 391 392 393 394 395 396 397 398 399
+        400 401 402 403 404 405 406 407 408 409 410 411 412 413 414 415
+        416 417 418 419 420 421 422 423 424 425 426 427 428 429 430 431
+        432 433 434 435 436 437 438 439 440 441 442 443 444 445 446 447
+        448 449 450 451 452 453 454 455 456 457 458 459 460 461 462 463
+        464 465 466 467 468 469 470 471 472 473 474 475 476 477 478 479
+        480 481 482 483 484 485 486 487 488 489 490 491 492 493 494 495
+        496 497 498 499 500 501 502 503 504 505 506 507 508 509 510 511
+    ]
+    [
+        512 513 514 515 516 517 518 519 520 521 522 523 524 525 526 527
+        528 529 530 531 532 533 534 535 536 537 538 539 540 541 542 543
+        544 545 546 547 548 549 550 551 552 553 554 555 556 557 558 559
+        560 561 562 563 564 565 566 567 568 569 570 571 572 573 574 575
+        576 577 578 579 580 581 582 583 584 585 586 587 588 589 590 591
+        592 593 594 595 596 597 598 599 600 601 602 603 604 605 606 607
+        608 609 610 611 612 613 614 615 616 617 618 619 620 621 622 623
+        624 625 626 627 628 629 630 631 632 633 634 635 636 637 638 639
+        640 641 642 643 644 645 646 647 648 649 650 651 652 653 654 655
+        656 657 658 659 660 661 662 663 664 665 666 667 668 669 670 671
+        672 673 674 675 676 677 678 679 680 681 682 683 684 685 686 687
+        688 689 690 691 692 693 694 695 696 697 698 699 700 701 702 703
+        704 705 706 707 708 709 710 711 712 713 714 715 716 717 718 719
+        720 721 722 723 724 725 726 727 728 729 730 731 732 733 734 735
+        736 737 738 739 740 741 742 743 744 745 746 747 748 749 750 751
+        752 753 754 755 756 757 758 759 760 761 762 763 764 765 766 767
+        768 769 770 771 772 773 774 775 776 777 778 779 780 781 782 783
+        784 785 786 787 788 789 790 791 792 793 794 795 796 797 798 799
+        800 801 802 803 804 805 806 807 808 809 810 811 812 813 814 815
+        816 817 818 819 820 821 822 823 824 825 826 827 828 829 830 831
+        832 833 834 835 836 837 838 839 840 841 842 843 844 845 846 847
+        848 849 850 851 852 853 854 855 856 857 858 859 860 861 862 863
+        864 865 866 867 868 869 870 871 872 873 874 875 876 877 878 879
+        880 881 882 883 884 885 886 887 888 889 890 891 892 893 894 895
+        896 897 898 899 900 901 902 903 904 905 906 907 908 909 910 911
+        912 913 914 915 916 917 918 919 920 921 922 923 924 925 926 927
+        928 929 930 931 932 933 934 935 936 937 938 939 940 941 942 943
+        944 945 946 947 948 949 950 951 952 953 954 955 956 957 958 959
+        960 961 962 963 964 965 966 967 968 969 970 971 972 973 974 975
+        976 977 978 979 980 981 982 983 984 985 986 987 988 989 990 991
+        992 993 994 995 996 997 998 999 1000 1001 1002 1003 1004 1005 1006 1007
+        1008 1009 1010 1011 1012 1013 1014 1015 1016 1017 1018 1019 1020 1021 1022 1023
+    ]
+);
+
+
+// --------==========[ Binary Arithmetic ]==========--------
+
+/// Binary counter multiplied by two
+pub trait Double: Index {
+    type Output: Index;
+}
+
+impl Double for Zero {
+    type Output = Zero;
+}
+
+impl<N: Index> Double for B0<N> {
+    type Output = B0<B0<N>>;
+}
+
+impl<N: Index> Double for B1<N> {
+    type Output = B0<B1<N>>;
+}
+
+impl<N: Index> Add<Zero> for B0<N> {
+    type Output = Self;
+}
+
+impl<N: Index> Add<Zero> for B1<N> {
+    type Output = Self;
+}
+
+impl<N: Add<M>, M: Index> Add<B0<M>> for B0<N> {
+    type Output = B0<N::Output>;
+}
+
+impl<N: Add<M>, M: Index> Add<B1<M>> for B0<N> {
+    type Output = B1<N::Output>;
+}
+
+impl<N: Add<M>, M: Index> Add<B0<M>> for B1<N> {
+    type Output = B1<N::Output>;
+}
+
+impl<N: Add<M>, M: Index> Add<B1<M>> for B1<N>
+where
+    N::Output: Increment,
+{
+    type Output = B0<<N::Output as Increment>::Output>;
+}
+
+impl<N: Sub<M>, M: Index> Sub<B0<M>> for B0<N>
+where
+    N::Output: Double,
+{
+    type Output = <N::Output as Double>::Output;
+}
+
+impl<N: Sub<M>, M: Index> Sub<B1<M>> for B1<N>
+where
+    N::Output: Double,
+{
+    type Output = <N::Output as Double>::Output;
+}
+
+impl<N: Sub<M>, M: Index> Sub<B0<M>> for B1<N> {
+    type Output = B1<N::Output>;
+}
+
+impl<N: Sub<M>, M: Index> Sub<B1<M>> for B0<N>
+where
+    N::Output: Decrement,
+{
+    type Output = B1<<N::Output as Decrement>::Output>;
+}
+
+impl<N: Mul<Rhs>, Rhs: Index> Mul<Rhs> for B0<N>
+where
+    N::Output: Double,
+{
+    type Output = <N::Output as Double>::Output;
+}
+
+impl<N: Mul<Rhs>, Rhs: Index> Mul<Rhs> for B1<N>
+where
+    N::Output: Double,
+    Rhs: Add<<N::Output as Double>::Output>,
+{
+    type Output = Sum<Rhs, <N::Output as Double>::Output>;
+}
+
+impl<M: Index> Min<B0<M>> for Zero {
+    type Output = Zero;
+}
+
+impl<M: Index> Min<B1<M>> for Zero {
+    type Output = Zero;
+}
+
+impl<M: Index> Max<B0<M>> for Zero {
+    type Output = B0<M>;
+}
+
+impl<M: Index> Max<B1<M>> for Zero {
+    type Output = B1<M>;
+}
+
+macro_rules! impl_binary_min_max {
+    ($(($lhs:ident, $rhs:ident))*) => {
+        $(
+            impl<N: Index, M: Index> Min<$rhs<M>> for $lhs<N>
+            where
+                Self: Lt<$rhs<M>>,
+                If<<Self as Lt<$rhs<M>>>::Output, Self, $rhs<M>>: Index,
+            {
+                type Output = If<<Self as Lt<$rhs<M>>>::Output, Self, $rhs<M>>;
+            }
+
+            impl<N: Index, M: Index> Max<$rhs<M>> for $lhs<N>
+            where
+                Self: Lt<$rhs<M>>,
+                If<<Self as Lt<$rhs<M>>>::Output, $rhs<M>, Self>: Index,
+            {
+                type Output = If<<Self as Lt<$rhs<M>>>::Output, $rhs<M>, Self>;
+            }
+        )*
+    };
+}
+
+impl_binary_min_max!((B0, B0) (B0, B1) (B1, B0) (B1, B1));
+
+
+// --------==========[ Binary Comparison ]==========--------
+
+impl<M: Index> Eq<B0<M>> for Zero {
+    type Output = False;
+}
+
+impl<M: Index> Eq<B1<M>> for Zero {
+    type Output = False;
+}
+
+impl<N: Index> Eq<Zero> for B0<N> {
+    type Output = False;
+}
+
+impl<N: Index> Eq<Zero> for B1<N> {
+    type Output = False;
+}
+
+impl<N: Eq<M>, M: Index> Eq<B0<M>> for B0<N> {
+    type Output = N::Output;
+}
+
+impl<N: Eq<M>, M: Index> Eq<B1<M>> for B1<N> {
+    type Output = N::Output;
+}
+
+impl<N: Index, M: Index> Eq<B1<M>> for B0<N> {
+    type Output = False;
+}
+
+impl<N: Index, M: Index> Eq<B0<M>> for B1<N> {
+    type Output = False;
+}
+
+impl<M: Index> Lt<B0<M>> for Zero {
+    type Output = True;
+}
+
+impl<M: Index> Lt<B1<M>> for Zero {
+    type Output = True;
+}
+
+impl<N: Index> Lt<Zero> for B0<N> {
+    type Output = False;
+}
+
+impl<N: Index> Lt<Zero> for B1<N> {
+    type Output = False;
+}
+
+impl<N: Lt<M>, M: Index> Lt<B0<M>> for B0<N> {
+    type Output = N::Output;
+}
+
+impl<N: Lt<M>, M: Index> Lt<B1<M>> for B1<N> {
+    type Output = N::Output;
+}
+
+/// Higher bits decide, equal ones leave `2n + 1 < 2m` false
+impl<N: Lt<M>, M: Index> Lt<B0<M>> for B1<N> {
+    type Output = N::Output;
+}
+
+/// Higher bits decide, equal ones leave `2n < 2m + 1` true
+impl<N: Le<M>, M: Index> Lt<B1<M>> for B0<N> {
+    type Output = N::Output;
+}
+
+impl<N: Index> Le<Zero> for B0<N> {
+    type Output = False;
+}
+
+impl<N: Index> Le<Zero> for B1<N> {
+    type Output = False;
+}
+
+impl<N: Le<M>, M: Index> Le<B0<M>> for B0<N> {
+    type Output = N::Output;
+}
+
+impl<N: Le<M>, M: Index> Le<B1<M>> for B1<N> {
+    type Output = N::Output;
+}
+
+impl<N: Le<M>, M: Index> Le<B1<M>> for B0<N> {
+    type Output = N::Output;
+}
+
+impl<N: Lt<M>, M: Index> Le<B0<M>> for B1<N> {
+    type Output = N::Output;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Compiles only for normalized counters, `B0<Zero>` has no `ToConst`
+    fn normalized<C: ToConst<Const = Const<N>>, const N: usize>() { }
+
+    macro_rules! check_arithmetic {
+        ($counter:ident; $(($a:literal, $b:literal))*) => {
+            $(
+                assert_eq!(<Sum<$counter<$a>, $counter<$b>> as Index>::INDEX, $a + $b);
+                assert_eq!(<Prod<$counter<$a>, $counter<$b>> as Index>::INDEX, $a * $b);
+                assert_eq!(<Minimum<$counter<$a>, $counter<$b>> as Index>::INDEX, usize::min($a, $b));
+                assert_eq!(<Maximum<$counter<$a>, $counter<$b>> as Index>::INDEX, usize::max($a, $b));
+                assert_eq!(
+                    <Diff<Maximum<$counter<$a>, $counter<$b>>, Minimum<$counter<$a>, $counter<$b>>> as Index>::INDEX,
+                    usize::abs_diff($a, $b),
+                );
+                normalized::<Sum<$counter<$a>, $counter<$b>>, { $a + $b }>();
+                normalized::<Diff<Maximum<$counter<$a>, $counter<$b>>, Minimum<$counter<$a>, $counter<$b>>>, { usize::abs_diff($a, $b) }>();
+            )*
+        };
+    }
+
+    macro_rules! check_comparison {
+        ($counter:ident; $(($a:literal, $b:literal))*) => {
+            $(
+                assert_eq!(<<$counter<$a> as Eq<$counter<$b>>>::Output as Bool>::VALUE, $a == $b);
+                assert_eq!(<<$counter<$a> as Lt<$counter<$b>>>::Output as Bool>::VALUE, $a < $b);
+                assert_eq!(<<$counter<$a> as Le<$counter<$b>>>::Output as Bool>::VALUE, $a <= $b);
+                assert_eq!(
+                    <If<<$counter<$a> as Lt<$counter<$b>>>::Output, $counter<$a>, $counter<$b>> as Index>::INDEX,
+                    if $a < $b { $a } else { $b },
+                );
+            )*
+        };
+    }
+
+    #[test]
+    fn binary_arithmetic_and_comparison() {
+        macro_rules! pairs {
+            ($check:ident) => {
+                $check!(Binary;
+                    (0, 0) (0, 1) (1, 0) (1, 1) (2, 1) (1, 2) (2, 2) (3, 2) (2, 3)
+                    (4, 3) (3, 4) (5, 5) (6, 7) (7, 6) (8, 1) (1, 8) (12, 10) (10, 12)
+                    (15, 16) (16, 15) (31, 1) (200, 199) (513, 510) (1000, 23)
+                )
+            };
+        }
+        pairs!(check_arithmetic);
+        pairs!(check_comparison);
+    }
+
+    #[test]
+    fn unary_arithmetic() {
+        check_arithmetic!(Counter;
+            (0, 0) (0, 1) (1, 0) (1, 1) (2, 3) (3, 2) (4, 4) (7, 9) (9, 7) (11, 11) (1, 100) (60, 2)
+        );
+    }
+
+    #[test]
+    fn unary_comparison() {
+        check_comparison!(Counter;
+            (0, 0) (0, 1) (1, 0) (1, 1) (2, 3) (3, 2) (4, 4) (7, 9) (9, 7) (126, 127) (127, 126) (127, 127)
+        );
+    }
+
+    #[test]
+    fn const_bridge() {
+        macro_rules! check_bridge {
+            ($($n:literal)*) => {
+                $(
+                    assert_eq!(<Counter<$n> as Index>::INDEX, $n);
+                    normalized::<Counter<$n>, $n>();
+                    normalized::<Binary<$n>, $n>();
+                )*
+            };
+        }
+        check_bridge!(0 1 2 3 4 5 31 32 63 64 100 126 127);
+    }
+
+    #[test]
+    fn binary_matches_unary() {
+        assert_eq!(<Sum<Counter<100>, Counter<27>> as Index>::INDEX, <Sum<Binary<100>, Binary<27>> as Index>::INDEX);
+        assert_eq!(<<Counter<5> as IntoBinary>::Binary as Index>::INDEX, 5);
+        assert_eq!(<<Binary<37> as IntoUnary>::Unary as Index>::INDEX, 37);
+    }
+}
@@ -0,0 +1,228 @@
+use std::any::Any;
+
+use super::counters;
+use super::indexed::{lhlist, Indexed};
+use super::uniform::{Uniform, UniformBackend};
+
+
+// --------==========[ Dirty Values ]==========--------
+
+/// Value with flag telling whether it changed since the last upload.
+///
+/// New values are dirty as they were never uploaded.
+pub struct Dirty<T> {
+    value: T,
+    dirty: bool,
+}
+
+impl<T> Dirty<T> {
+    pub fn new(value: T) -> Self {
+        Self { value, dirty: true }
+    }
+
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Mutable access marks value dirty.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.dirty = true;
+        &mut self.value
+    }
+
+    /// Replace value and mark it dirty, returns the previous one.
+    pub fn set(&mut self, value: T) -> T {
+        self.dirty = true;
+        std::mem::replace(&mut self.value, value)
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+
+// --------==========[ Tracking ]==========--------
+
+/// Wrapping every element of indexed LHList into `Dirty`
+pub trait Track: lhlist::Base {
+    type Tracked: lhlist::Base;
+
+    fn track(self) -> Self::Tracked;
+}
+
+/// Base case
+impl Track for () {
+    type Tracked = ();
+
+    fn track(self) -> Self::Tracked { }
+}
+
+/// Inductive step
+impl<const INDEX: usize, H: Track, U> Track for (H, Indexed<INDEX, U>) {
+    type Tracked = (H::Tracked, Indexed<INDEX, Dirty<U>>);
+
+    fn track(self) -> Self::Tracked {
+        let (head, elem) = self;
+        (head.track(), Indexed::new(Dirty::new(elem.into_inner())))
+    }
+}
+
+/// Access to dirty elements of tracked list
+pub trait DirtyElems: lhlist::Base {
+    /// Upload dirty elements and clear their flags, returns number of uploaded elements.
+    fn upload_dirty<B: UniformBackend + ?Sized>(&mut self, backend: &mut B) -> usize;
+
+    fn collect_dirty<'a>(&'a self, entries: &mut Vec<(usize, &'a dyn Any)>);
+
+    fn mark_all(&mut self);
+}
+
+/// Base case
+impl DirtyElems for () {
+    fn upload_dirty<B: UniformBackend + ?Sized>(&mut self, _: &mut B) -> usize {
+        0
+    }
+
+    fn collect_dirty<'a>(&'a self, _: &mut Vec<(usize, &'a dyn Any)>) { }
+
+    fn mark_all(&mut self) { }
+}
+
+/// Inductive step
+impl<const INDEX: usize, H: DirtyElems, U: Uniform + Any> DirtyElems for (H, Indexed<INDEX, Dirty<U>>) {
+    fn upload_dirty<B: UniformBackend + ?Sized>(&mut self, backend: &mut B) -> usize {
+        let uploaded = self.0.upload_dirty(backend);
+        let elem = self.1.get_mut();
+        if !elem.dirty {
+            return uploaded;
+        }
+        elem.value.upload(INDEX, backend);
+        elem.dirty = false;
+        uploaded + 1
+    }
+
+    fn collect_dirty<'a>(&'a self, entries: &mut Vec<(usize, &'a dyn Any)>) {
+        self.0.collect_dirty(entries);
+        let elem = self.1.get();
+        if elem.dirty {
+            entries.push((INDEX, &elem.value));
+        }
+    }
+
+    fn mark_all(&mut self) {
+        self.0.mark_all();
+        self.1.get_mut().dirty = true;
+    }
+}
+
+
+// --------==========[ Tracked Uniforms ]==========--------
+
+/// Uniforms re-uploaded only when they changed.
+pub struct Tracked<US> {
+    uniforms: US,
+}
+
+impl<US: DirtyElems> Tracked<US> {
+    /// Track indexed LHList, all elements start dirty.
+    pub fn new<L: Track<Tracked = US>>(uniforms: L) -> Self {
+        Self { uniforms: uniforms.track() }
+    }
+
+    pub fn get<const INDEX: usize, U, I>(&self) -> &U
+    where
+        US: lhlist::Selector<INDEX, Dirty<U>, I>,
+        I: counters::Index,
+    {
+        self.uniforms.select().get()
+    }
+
+    /// Mutable access marks uniform dirty.
+    pub fn get_mut<const INDEX: usize, U, I>(&mut self) -> &mut U
+    where
+        US: lhlist::Selector<INDEX, Dirty<U>, I>,
+        I: counters::Index,
+    {
+        self.uniforms.select_mut().get_mut()
+    }
+
+    /// Replace uniform and mark it dirty, returns the previous value.
+    pub fn set<const INDEX: usize, U, I>(&mut self, value: U) -> U
+    where
+        US: lhlist::Selector<INDEX, Dirty<U>, I>,
+        I: counters::Index,
+    {
+        self.uniforms.select_mut().set(value)
+    }
+
+    pub fn is_dirty<const INDEX: usize, U, I>(&self) -> bool
+    where
+        US: lhlist::Selector<INDEX, Dirty<U>, I>,
+        I: counters::Index,
+    {
+        self.uniforms.select().is_dirty()
+    }
+
+    /// Type erased dirty uniforms with their index, in list order.
+    pub fn iter_dirty(&self) -> impl Iterator<Item = (usize, &dyn Any)> {
+        let mut entries = Vec::with_capacity(US::LENGTH);
+        self.uniforms.collect_dirty(&mut entries);
+        entries.into_iter()
+    }
+
+    /// Upload dirty uniforms only, returns number of uploaded uniforms.
+    pub fn upload<B: UniformBackend + ?Sized>(&mut self, backend: &mut B) -> usize {
+        self.uniforms.upload_dirty(backend)
+    }
+
+    /// Force upload of all uniforms, e.g. after context loss.
+    pub fn mark_all(&mut self) {
+        self.uniforms.mark_all()
+    }
+
+    pub fn into_inner(self) -> US {
+        self.uniforms
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uniform::{Call, RecordingBackend, Scalar};
+
+    #[test]
+    fn only_dirty_uniforms_are_uploaded() {
+        let uniforms = ((((), Indexed::<0, f32>::new(1.0)), Indexed::<1, [f32; 2]>::new([0.0; 2])), Indexed::<500, u32>::new(3));
+        let mut tracked = Tracked::new(uniforms);
+        let mut backend = RecordingBackend::new();
+
+        // Everything is dirty before the first upload
+        assert_eq!(tracked.upload(&mut backend), 3);
+        assert_eq!(tracked.iter_dirty().count(), 0);
+        assert_eq!(tracked.upload(&mut backend), 0);
+
+        backend.calls.clear();
+        *tracked.get_mut::<1, [f32; 2], _>() = [1.0, 2.0];
+        assert_eq!(tracked.set::<500, u32, _>(4), 3);
+        assert_eq!(*tracked.get::<0, f32, _>(), 1.0);
+        assert!(!tracked.is_dirty::<0, f32, _>());
+
+        let dirty: Vec<_> = tracked.iter_dirty().map(|(index, _)| index).collect();
+        assert_eq!(dirty, [1, 500]);
+
+        assert_eq!(tracked.upload(&mut backend), 2);
+        assert_eq!(backend.calls, [
+            Call::Vector { location: 1, value: vec![Scalar::F32(1.0), Scalar::F32(2.0)] },
+            Call::Scalar { location: 500, value: Scalar::U32(4) },
+        ]);
+
+        tracked.mark_all();
+        assert_eq!(tracked.upload(&mut backend), 3);
+    }
+}
@@ -0,0 +1,203 @@
+use std::any::Any;
+use std::marker::PhantomData;
+
+use super::{counters, rhlist};
+
+
+// --------==========[ Handlers ]==========--------
+
+/// Handler closure together with the event type it accepts.
+pub struct Handler<E, F> {
+    handler: F,
+    event: PhantomData<fn(&E)>,
+}
+
+impl<E, F: FnMut(&E)> Handler<E, F> {
+    pub fn new(handler: F) -> Self {
+        Self { handler, event: PhantomData }
+    }
+}
+
+
+// --------==========[ Handler Lists ]==========--------
+
+/// Conversion of RHList of closures into RHList of `Handler`s.
+///
+/// `Events` is RHList of event types, one per closure.
+pub trait IntoHandlers<Events>: rhlist::Base {
+    type Handlers: Dispatch;
+
+    fn into_handlers(self) -> Self::Handlers;
+}
+
+/// Base case
+impl IntoHandlers<()> for () {
+    type Handlers = ();
+
+    fn into_handlers(self) -> Self::Handlers { }
+}
+
+/// Inductive step
+impl<F, E, T, Es> IntoHandlers<(E, Es)> for (F, T)
+where
+    F: FnMut(&E),
+    E: 'static,
+    T: IntoHandlers<Es>,
+{
+    type Handlers = (Handler<E, F>, T::Handlers);
+
+    fn into_handlers(self) -> Self::Handlers {
+        let (handler, tail) = self;
+        (Handler::new(handler), tail.into_handlers())
+    }
+}
+
+
+// --------==========[ Distinct Types ]==========--------
+
+/// Every element of RHList occurs in `Full` exactly once.
+///
+/// `Is` holds position of each element in `Full`, and it is inferred.
+/// Type occurring twice yields two candidate positions
+/// which makes the inference ambiguous and fails compilation.
+pub trait Distinct<Full, Is> { }
+
+/// Base case
+impl<Full> Distinct<Full, ()> for () { }
+
+/// Inductive step
+impl<E, T, Full, I, Is> Distinct<Full, (I, Is)> for (E, T)
+where
+    Full: rhlist::Selector<E, I>,
+    T: Distinct<Full, Is>,
+    I: counters::Index,
+{ }
+
+
+// --------==========[ Dispatch ]==========--------
+
+/// Calling handler matching runtime type of the event
+pub trait Dispatch {
+    fn dispatch(&mut self, event: &dyn Any) -> bool;
+}
+
+/// Base case
+impl Dispatch for () {
+    fn dispatch(&mut self, _: &dyn Any) -> bool {
+        false
+    }
+}
+
+/// Inductive step
+impl<E: 'static, F: FnMut(&E), T: Dispatch> Dispatch for (Handler<E, F>, T) {
+    fn dispatch(&mut self, event: &dyn Any) -> bool {
+        match event.downcast_ref::<E>() {
+            Some(event) => {
+                (self.0.handler)(event);
+                true
+            }
+            None => self.1.dispatch(event),
+        }
+    }
+}
+
+/// Dispatcher of type erased events to handlers with distinct event types.
+pub struct Dispatcher<HS> {
+    handlers: HS,
+}
+
+impl<HS: Dispatch> Dispatcher<HS> {
+    /// Build Dispatcher from RHList of closures, e.g. `(|a: &A| ..., (|b: &B| ..., ()))`.
+    ///
+    /// Two closures accepting the same event type are rejected at compile time.
+    ///
+    /// ```
+    /// use hlist::dispatch::Dispatcher;
+    ///
+    /// let mut dispatcher = Dispatcher::new((|_: &u8| { }, (|_: &u16| { }, ())));
+    /// assert!(dispatcher.dispatch(&1u16));
+    /// assert!(!dispatcher.dispatch(&1u32));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use hlist::dispatch::Dispatcher;
+    ///
+    /// let dispatcher = Dispatcher::new((|_: &u8| { }, (|_: &u8| { }, ())));
+    /// ```
+    pub fn new<L, Events, Is>(handlers: L) -> Self
+    where
+        L: IntoHandlers<Events, Handlers = HS>,
+        Events: Distinct<Events, Is>,
+    {
+        Self { handlers: handlers.into_handlers() }
+    }
+
+    /// Call handler accepting runtime type of `event`, returns whether one fired.
+    pub fn dispatch(&mut self, event: &dyn Any) -> bool {
+        self.handlers.dispatch(event)
+    }
+
+    /// Same as `dispatch` for owned events.
+    ///
+    /// ```
+    /// use std::any::Any;
+    /// use hlist::dispatch::Dispatcher;
+    ///
+    /// let mut total = 0u32;
+    /// let mut dispatcher = Dispatcher::new((|value: &u8| total += u32::from(*value), (|_: &u16| { }, ())));
+    /// let events: Vec<Box<dyn Any>> = vec![Box::new(2u8), Box::new(3u16), Box::new(4u8), Box::new("other")];
+    /// let fired = events.into_iter().map(|event| dispatcher.dispatch_boxed(event)).collect::<Vec<_>>();
+    /// assert_eq!(fired, [true, true, true, false]);
+    /// drop(dispatcher);
+    /// assert_eq!(total, 6);
+    /// ```
+    pub fn dispatch_boxed(&mut self, event: Box<dyn Any>) -> bool {
+        self.handlers.dispatch(&*event)
+    }
+
+    pub fn into_inner(self) -> HS {
+        self.handlers
+    }
+}
+
+
+// --------==========[ Broadcast ]==========--------
+
+/// Calling every closure of RHList with the same event.
+///
+/// Meant for trait object events, handlers need the `'static` bound spelled out,
+/// e.g. `|event: &(dyn Event + 'static)| ...`.
+///
+/// ```
+/// use std::fmt::Debug;
+/// use hlist::dispatch::Broadcast;
+///
+/// let mut logged = Vec::new();
+/// let mut count = 0;
+/// let mut handlers = (
+///     |event: &(dyn Debug + 'static)| logged.push(format!("{event:?}")),
+///     (|_: &(dyn Debug + 'static)| count += 1, ()),
+/// );
+/// assert_eq!(handlers.broadcast(&"event" as &dyn Debug), 2);
+/// drop(handlers);
+/// assert_eq!((logged, count), (vec![String::from("\"event\"")], 1));
+/// ```
+pub trait Broadcast<E: ?Sized>: rhlist::Base {
+    /// Call all handlers, returns number of handlers called.
+    fn broadcast(&mut self, event: &E) -> usize;
+}
+
+/// Base case
+impl<E: ?Sized> Broadcast<E> for () {
+    fn broadcast(&mut self, _: &E) -> usize {
+        0
+    }
+}
+
+/// Inductive step
+impl<E: ?Sized, F: FnMut(&E), T: Broadcast<E>> Broadcast<E> for (F, T) {
+    fn broadcast(&mut self, event: &E) -> usize {
+        (self.0)(event);
+        1 + self.1.broadcast(event)
+    }
+}
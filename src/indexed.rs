@@ -1,3 +1,6 @@
+use std::any::Any;
+
+use super::counters;
 use super::lhlist as unordered_lhlist;
 use super::rhlist as unordered_rhlist;
 
@@ -8,11 +11,43 @@ use super::rhlist as unordered_rhlist;
 pub struct Indexed<const INDEX: usize, T>(T);
 
 impl<const INDEX: usize, T> Indexed<INDEX, T> {
+    pub const INDEX: usize = INDEX;
+
     pub fn new(value: T) -> Self {
         Self(value)
     }
+
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
 }
 
+/// Construction of `Indexed` from `counters::Const`
+pub trait Wrap {
+    type Indexed<T>;
+
+    fn wrap<T>(value: T) -> Self::Indexed<T>;
+}
+
+impl<const INDEX: usize> Wrap for counters::Const<INDEX> {
+    type Indexed<T> = Indexed<INDEX, T>;
+
+    fn wrap<T>(value: T) -> Self::Indexed<T> {
+        Indexed::new(value)
+    }
+}
+
+/// `Indexed` with index given by type level counter
+pub type IndexedAt<C, T> = <<C as counters::ToConst>::Const as Wrap>::Indexed<T>;
+
 pub mod lhlist {
     use super::*;
 
@@ -36,9 +71,7 @@ pub mod lhlist {
     }
     
     impl Empty for () {
-        fn default() -> Self {
-            ()
-        }
+        fn default() -> Self { }
     
         fn new<E>(value: E) -> (Self, Indexed<0, E>) {
             ((), Indexed::new(value))
@@ -70,60 +103,1412 @@ pub mod lhlist {
             (self, Indexed::new(value))
         }
     }
-}
 
-pub mod rhlist {
-    use super::*;
 
-    // TODO: Add Factory functions
+    // --------==========[ Index HList Prepend ]==========--------
 
-    // FIXME: Should we use `unordered_rhlist::Base` here?
-    pub trait Base: unordered_rhlist::Base { }
-    
-    impl Base for () { }
-    impl<const INDEX: usize, E, T: Base> Base for (Indexed<INDEX, E>, T) { }
+    /// Prepending new Indexed Element at the front of LHList
+    pub trait Prepend<E>: Base {
+        type Prepended<const INDEX: usize>: Base;
 
+        fn prepend_indexed<const INDEX: usize>(self, value: E) -> Self::Prepended<INDEX>;
+    }
 
-    pub trait Empty: Base {
-        fn default() -> Self;
+    /// Base case
+    impl<E> Prepend<E> for () {
+        type Prepended<const INDEX: usize> = ((), Indexed<INDEX, E>);
 
-        fn prepend_indexed<E>(self, value: E) -> (Indexed<0, E>, Self);
+        fn prepend_indexed<const INDEX: usize>(self, value: E) -> Self::Prepended<INDEX> {
+            ((), Indexed::new(value))
+        }
+    }
 
-        fn new<E>(value: E) -> (Indexed<0, E>, Self);
+    /// Inductive step
+    impl<const I: usize, H: Prepend<E>, X, E> Prepend<E> for (H, Indexed<I, X>) {
+        type Prepended<const INDEX: usize> = (H::Prepended<INDEX>, Indexed<I, X>);
+
+        fn prepend_indexed<const INDEX: usize>(self, value: E) -> Self::Prepended<INDEX> {
+            let (head, elem) = self;
+            (head.prepend_indexed::<INDEX>(value), elem)
+        }
     }
 
-    impl Empty for () {
-        fn default() -> Self {
-            ()
+
+    // --------==========[ Index HList Append Next ]==========--------
+
+    /// Appending new element indexed with current length of the LHList
+    ///
+    /// Length is converted to binary counter, see [`counters::Binary`] for the limit.
+    ///
+    /// ```
+    /// # #![recursion_limit = "1024"]
+    /// use hlist::indexed::{lhlist::AppendNext, Indexed};
+    ///
+    /// macro_rules! append {
+    ///     ($list:expr;) => { $list };
+    ///     ($list:expr; $value:literal $($rest:literal)*) => { append!($list.append_next($value); $($rest)*) };
+    /// }
+    ///
+    /// let list = append!((); 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+    ///     32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+    ///     64 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95
+    ///     96 97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127 128);
+    /// let last: Indexed<128, i32> = list.1;
+    /// assert_eq!(*last.get(), 128);
+    /// ```
+    pub trait AppendNext<E>: Base {
+        type Appended;
+
+        fn append_next(self, value: E) -> Self::Appended;
+    }
+
+    impl<L: Base, E> AppendNext<E> for L
+    where
+        L::Length: counters::IntoBinary,
+        NextIndex<L>: counters::ToConst,
+        <NextIndex<L> as counters::ToConst>::Const: Wrap,
+    {
+        type Appended = (L, IndexedAt<NextIndex<L>, E>);
+
+        fn append_next(self, value: E) -> Self::Appended {
+            (self, <<NextIndex<L> as counters::ToConst>::Const as Wrap>::wrap(value))
         }
+    }
 
-        fn new<E>(value: E) -> (Indexed<0, E>, Self) {
-            (Indexed::new(value), ())
+    /// Length of the LHList as binary counter
+    type NextIndex<L> = <<L as unordered_lhlist::Base>::Length as counters::IntoBinary>::Binary;
+
+
+    // --------==========[ Inversion LHList <-> RHList ]==========--------
+
+    /// Indexed LHList conversion to indexed RHList
+    pub trait Invert: Base {
+        type Inverted: super::rhlist::Base;
+
+        fn invert(self) -> Self::Inverted;
+    }
+
+    /// Base case
+    impl Invert for () {
+        type Inverted = ();
+
+        fn invert(self) -> Self::Inverted {
+            self
         }
+    }
 
-        fn prepend_indexed<E>(self, value: E) -> (Indexed<0, E>, Self) {
-            <Self as Empty>::new(value)
+    /// Inductive step
+    impl<const INDEX: usize, H: Invert, E> Invert for (H, Indexed<INDEX, E>)
+    where
+        H::Inverted: super::rhlist::Append<E>,
+    {
+        type Inverted = <H::Inverted as super::rhlist::Append<E>>::Appended<INDEX>;
+
+        fn invert(self) -> Self::Inverted {
+            let (head, Indexed(elem)) = self;
+            super::rhlist::Append::append_indexed::<INDEX>(head.invert(), elem)
         }
     }
 
-    pub fn new() -> impl Empty {
-        <() as Empty>::default()
+
+    // --------==========[ HList Reversion ]==========--------
+
+    /// Reverse indexed LHList
+    pub trait Reverse: Base {
+        type Reversed: Base;
+
+        fn reverse(self) -> Self::Reversed;
     }
 
+    /// Base case
+    impl Reverse for () {
+        type Reversed = ();
 
-    // --------==========[ Index HList Append ]==========--------
-    
-    pub trait Append<E>: Base {
-        fn prepend_indexed<const INDEX: usize>(self, value: E) -> (Indexed<INDEX, E>, Self);
+        fn reverse(self) -> Self::Reversed {
+            self
+        }
     }
-    
-    impl<H, T, E> Append<E> for (H, T)
+
+    /// Inductive step
+    impl<const INDEX: usize, H: Reverse, E> Reverse for (H, Indexed<INDEX, E>)
     where
-        (H, T): Base
+        H::Reversed: Prepend<E>,
     {
-        fn prepend_indexed<const INDEX: usize>(self, value: E) -> (Indexed<INDEX, E>, Self) {
-            (Indexed::new(value), self)
+        type Reversed = <H::Reversed as Prepend<E>>::Prepended<INDEX>;
+
+        fn reverse(self) -> Self::Reversed {
+            let (head, Indexed(elem)) = self;
+            head.reverse().prepend_indexed::<INDEX>(elem)
+        }
+    }
+
+
+    // --------==========[ Index Removal ]==========--------
+
+    /// Conversion to plain LHList by stripping `Indexed` wrappers
+    pub trait Unindex: Base {
+        type Unindexed: unordered_lhlist::Base;
+
+        fn unindex(self) -> Self::Unindexed;
+    }
+
+    /// Base case
+    impl Unindex for () {
+        type Unindexed = ();
+
+        fn unindex(self) -> Self::Unindexed {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, H: Unindex, E> Unindex for (H, Indexed<INDEX, E>) {
+        type Unindexed = (H::Unindexed, E);
+
+        fn unindex(self) -> Self::Unindexed {
+            let (head, Indexed(elem)) = self;
+            (head.unindex(), elem)
+        }
+    }
+
+
+    // --------==========[ Sorting ]==========--------
+
+    /// Ordering of elements by `INDEX`, duplicated indices fail to compile
+    ///
+    /// ```
+    /// use hlist::indexed::{lhlist::Sort, Indexed};
+    ///
+    /// let (((), a), b) = (((), Indexed::<900, u8>::new(1)), Indexed::<2, u16>::new(2)).sort();
+    /// assert_eq!((*a.get(), *b.get()), (2, 1));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use hlist::indexed::{lhlist::Sort, Indexed};
+    ///
+    /// let sorted = (((), Indexed::<2, u8>::new(1)), Indexed::<2, u16>::new(2)).sort();
+    /// ```
+    pub trait Sort: Base {
+        type Sorted: Base;
+
+        fn sort(self) -> Self::Sorted;
+    }
+
+    /// Sorting is done on inverted list
+    impl<L: Invert> Sort for L
+    where
+        L::Inverted: super::rhlist::Sort,
+        <L::Inverted as super::rhlist::Sort>::Sorted: super::rhlist::Invert,
+    {
+        type Sorted = <<L::Inverted as super::rhlist::Sort>::Sorted as super::rhlist::Invert>::Inverted;
+
+        fn sort(self) -> Self::Sorted {
+            super::rhlist::Invert::invert(super::rhlist::Sort::sort(self.invert()))
+        }
+    }
+
+
+    // --------==========[ Concatenation ]==========--------
+
+    /// Offsetting every index by counter `C`, unary or binary
+    ///
+    /// Shifted indices are computed as [`counters::Binary`] counters.
+    pub trait Shift<C: counters::Index>: Base {
+        type Shifted;
+
+        fn shift(self) -> Self::Shifted;
+    }
+
+    /// Base case
+    impl<C: counters::Index> Shift<C> for () {
+        type Shifted = ();
+
+        fn shift(self) -> Self::Shifted {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, H, E, C> Shift<C> for (H, Indexed<INDEX, E>)
+    where
+        H: Shift<C>,
+        C: counters::Index + counters::IntoBinary,
+        counters::Const<INDEX>: counters::IntoBinary,
+        counters::Binary<INDEX>: counters::Add<C::Binary>,
+        counters::Sum<counters::Binary<INDEX>, C::Binary>: counters::ToConst,
+        <counters::Sum<counters::Binary<INDEX>, C::Binary> as counters::ToConst>::Const: Wrap,
+    {
+        type Shifted = (H::Shifted, IndexedAt<counters::Sum<counters::Binary<INDEX>, C::Binary>, E>);
+
+        fn shift(self) -> Self::Shifted {
+            let (head, Indexed(elem)) = self;
+            let elem = <<counters::Sum<counters::Binary<INDEX>, C::Binary> as counters::ToConst>::Const as Wrap>::wrap(elem);
+            (head.shift(), elem)
+        }
+    }
+
+    /// Concatenation keeping indices of both lists unchanged
+    pub trait Chain<R>: Base {
+        type Chained: Base;
+
+        fn chain(self, rhs: R) -> Self::Chained;
+    }
+
+    /// Base case
+    impl<L: Base> Chain<()> for L {
+        type Chained = L;
+
+        fn chain(self, _: ()) -> Self::Chained {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, L, H, E> Chain<(H, Indexed<INDEX, E>)> for L
+    where
+        L: Chain<H>,
+    {
+        type Chained = (L::Chained, Indexed<INDEX, E>);
+
+        fn chain(self, rhs: (H, Indexed<INDEX, E>)) -> Self::Chained {
+            let (head, elem) = rhs;
+            (self.chain(head), elem)
+        }
+    }
+
+    /// Concatenation with indices of `rhs` offset by length of `self`
+    pub trait Concat<R>: Base {
+        type Concatenated: Base;
+
+        fn concat(self, rhs: R) -> Self::Concatenated;
+    }
+
+    impl<L: Base, R> Concat<R> for L
+    where
+        R: Shift<<L as unordered_lhlist::Base>::Length>,
+        L: Chain<<R as Shift<<L as unordered_lhlist::Base>::Length>>::Shifted>,
+    {
+        type Concatenated = <L as Chain<<R as Shift<<L as unordered_lhlist::Base>::Length>>::Shifted>>::Chained;
+
+        fn concat(self, rhs: R) -> Self::Concatenated {
+            self.chain(rhs.shift())
+        }
+    }
+
+    /// Concatenation with indices of `rhs` offset by const `OFFSET`
+    ///
+    /// ```
+    /// use hlist::indexed::{lhlist::ConcatOffset, Indexed};
+    ///
+    /// let list: (((), Indexed<0, u8>), Indexed<900, u16>) =
+    ///     ((), Indexed::<0, u8>::new(1)).concat_offset::<500>(((), Indexed::<400, u16>::new(2)));
+    /// ```
+    pub trait ConcatOffset<R>: Base {
+        fn concat_offset<const OFFSET: usize>(self, rhs: R) -> <Self as Chain<<R as Shift<counters::Binary<OFFSET>>>::Shifted>>::Chained
+        where
+            counters::Const<OFFSET>: counters::IntoBinary,
+            R: Shift<counters::Binary<OFFSET>>,
+            Self: Chain<R::Shifted>;
+    }
+
+    impl<L: Base, R> ConcatOffset<R> for L {
+        fn concat_offset<const OFFSET: usize>(self, rhs: R) -> <Self as Chain<<R as Shift<counters::Binary<OFFSET>>>::Shifted>>::Chained
+        where
+            counters::Const<OFFSET>: counters::IntoBinary,
+            R: Shift<counters::Binary<OFFSET>>,
+            Self: Chain<R::Shifted>,
+        {
+            self.chain(rhs.shift())
+        }
+    }
+
+
+    // --------==========[ Index HList Selectors ]==========--------
+
+    /// Selection of element by its const `INDEX` regardless of its position
+    pub trait Selector<const INDEX: usize, Needle, I>: Base
+    where
+        I: counters::Index
+    {
+        fn select(&self) -> &Needle;
+
+        fn select_mut(&mut self) -> &mut Needle;
+    }
+
+    impl<const INDEX: usize, H: Base, Needle> Selector<INDEX, Needle, counters::Zero> for (H, Indexed<INDEX, Needle>) {
+        fn select(&self) -> &Needle {
+            &self.1.0
+        }
+
+        fn select_mut(&mut self) -> &mut Needle {
+            &mut self.1.0
+        }
+    }
+
+    impl<const INDEX: usize, H, T, Needle, I> Selector<INDEX, Needle, counters::Successor<I>> for (H, T)
+    where
+        H: Selector<INDEX, Needle, I>,
+        (H, T): Base,
+        I: counters::Index,
+    {
+        fn select(&self) -> &Needle {
+            self.0.select()
+        }
+
+        fn select_mut(&mut self) -> &mut Needle {
+            self.0.select_mut()
+        }
+    }
+
+    /// Getters by const `INDEX` with element type inferred, e.g. `list.get::<3>()`
+    pub trait IndexSelector<Needle, I>: Base {
+        fn get<const INDEX: usize>(&self) -> &Needle
+        where
+            Self: Selector<INDEX, Needle, I>,
+            I: counters::Index;
+
+        fn get_mut<const INDEX: usize>(&mut self) -> &mut Needle
+        where
+            Self: Selector<INDEX, Needle, I>,
+            I: counters::Index;
+    }
+
+    impl<L: Base, Needle, I> IndexSelector<Needle, I> for L {
+        fn get<const INDEX: usize>(&self) -> &Needle
+        where
+            Self: Selector<INDEX, Needle, I>,
+            I: counters::Index,
+        {
+            self.select()
+        }
+
+        fn get_mut<const INDEX: usize>(&mut self) -> &mut Needle
+        where
+            Self: Selector<INDEX, Needle, I>,
+            I: counters::Index,
+        {
+            self.select_mut()
+        }
+    }
+
+    // --------==========[ Index HList Removal ]==========--------
+
+    /// Removal of element by its const `INDEX` regardless of its position
+    pub trait Remover<const INDEX: usize, Needle, I>: Base
+    where
+        I: counters::Index
+    {
+        type Remainder: Base;
+
+        fn remove(self) -> (Needle, Self::Remainder);
+    }
+
+    /// Base case
+    impl<const INDEX: usize, H: Base, Needle> Remover<INDEX, Needle, counters::Zero> for (H, Indexed<INDEX, Needle>) {
+        type Remainder = H;
+
+        fn remove(self) -> (Needle, Self::Remainder) {
+            let (head, Indexed(elem)) = self;
+            (elem, head)
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, const OTHER: usize, H, E, Needle, I> Remover<INDEX, Needle, counters::Successor<I>> for (H, Indexed<OTHER, E>)
+    where
+        H: Remover<INDEX, Needle, I>,
+        I: counters::Index,
+    {
+        type Remainder = (H::Remainder, Indexed<OTHER, E>);
+
+        fn remove(self) -> (Needle, Self::Remainder) {
+            let (head, elem) = self;
+            let (needle, head) = head.remove();
+            (needle, (head, elem))
+        }
+    }
+
+
+    // --------==========[ Runtime Index Selectors ]==========--------
+
+    /// Selection by `INDEX` known only at runtime
+    ///
+    /// Duplicated indices select the element first in position order.
+    ///
+    /// ```
+    /// use hlist::indexed::{lhlist::DynSelector, Indexed};
+    ///
+    /// let list = (((), Indexed::<1, u8>::new(1)), Indexed::<1, u16>::new(2));
+    /// assert!(list.get_dyn(1).unwrap().is::<u8>());
+    /// ```
+    pub trait DynSelector: Base {
+        fn get_dyn(&self, index: usize) -> Option<&dyn Any>;
+
+        fn get_dyn_mut(&mut self, index: usize) -> Option<&mut dyn Any>;
+
+        fn get_as<T: Any>(&self, index: usize) -> Option<&T> {
+            self.get_dyn(index)?.downcast_ref()
+        }
+
+        fn get_as_mut<T: Any>(&mut self, index: usize) -> Option<&mut T> {
+            self.get_dyn_mut(index)?.downcast_mut()
+        }
+    }
+
+    /// Base case
+    impl DynSelector for () {
+        fn get_dyn(&self, _: usize) -> Option<&dyn Any> {
+            None
+        }
+
+        fn get_dyn_mut(&mut self, _: usize) -> Option<&mut dyn Any> {
+            None
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, H: DynSelector, E: Any> DynSelector for (H, Indexed<INDEX, E>) {
+        fn get_dyn(&self, index: usize) -> Option<&dyn Any> {
+            let (head, Indexed(elem)) = self;
+            match head.get_dyn(index) {
+                Some(found) => Some(found),
+                None if index == INDEX => Some(elem),
+                None => None,
+            }
+        }
+
+        fn get_dyn_mut(&mut self, index: usize) -> Option<&mut dyn Any> {
+            let (head, Indexed(elem)) = self;
+            match head.get_dyn_mut(index) {
+                Some(found) => Some(found),
+                None if index == INDEX => Some(elem),
+                None => None,
+            }
+        }
+    }
+
+
+    /// Type erased view of all elements paired with their `INDEX`, in position order
+    pub trait Entries: Base {
+        fn collect_entries<'a>(&'a self, entries: &mut Vec<(usize, &'a dyn Any)>);
+
+        fn entries(&self) -> Vec<(usize, &dyn Any)> {
+            let mut entries = Vec::with_capacity(Self::LENGTH);
+            self.collect_entries(&mut entries);
+            entries
+        }
+    }
+
+    /// Base case
+    impl Entries for () {
+        fn collect_entries<'a>(&'a self, _: &mut Vec<(usize, &'a dyn Any)>) { }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, H: Entries, E: Any> Entries for (H, Indexed<INDEX, E>) {
+        fn collect_entries<'a>(&'a self, entries: &mut Vec<(usize, &'a dyn Any)>) {
+            self.0.collect_entries(entries);
+            entries.push((INDEX, &self.1.0));
+        }
+    }
+
+    // --------==========[ Merging ]==========--------
+
+    /// Presence of element indexed with `INDEX`, as type level boolean
+    ///
+    /// Indices are compared as [`counters::Binary`] counters.
+    pub trait HasIndex<const INDEX: usize>: Base {
+        type Output: counters::Bool;
+    }
+
+    /// Base case
+    impl<const INDEX: usize> HasIndex<INDEX> for () {
+        type Output = counters::False;
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, const OTHER: usize, H, E> HasIndex<INDEX> for (H, Indexed<OTHER, E>)
+    where
+        H: HasIndex<INDEX>,
+        counters::Const<INDEX>: counters::IntoBinary,
+        counters::Const<OTHER>: counters::IntoBinary,
+        counters::Binary<INDEX>: counters::Eq<counters::Binary<OTHER>>,
+        <counters::Binary<INDEX> as counters::Eq<counters::Binary<OTHER>>>::Output: counters::Or<H::Output>,
+    {
+        type Output = <<counters::Binary<INDEX> as counters::Eq<counters::Binary<OTHER>>>::Output as counters::Or<H::Output>>::Output;
+    }
+
+    /// Adding single element unless its `INDEX` is already present, `Present` tells which is the case
+    ///
+    /// Present element has to have the same type, otherwise there is no `Selector` for it.
+    pub trait MergeElem<E, Present: counters::Bool, I: counters::Index>: Base {
+        type Output: Base;
+
+        fn merge_elem(self, elem: E) -> Self::Output;
+    }
+
+    /// Element is present, it is dropped
+    impl<const INDEX: usize, L, U, I> MergeElem<Indexed<INDEX, U>, counters::True, I> for L
+    where
+        L: Selector<INDEX, U, I>,
+        I: counters::Index,
+    {
+        type Output = L;
+
+        fn merge_elem(self, _: Indexed<INDEX, U>) -> Self::Output {
+            self
+        }
+    }
+
+    /// Element is missing, it is appended
+    impl<const INDEX: usize, L: Base, U> MergeElem<Indexed<INDEX, U>, counters::False, counters::Zero> for L {
+        type Output = (L, Indexed<INDEX, U>);
+
+        fn merge_elem(self, elem: Indexed<INDEX, U>) -> Self::Output {
+            (self, elem)
+        }
+    }
+
+    /// Merging of all elements into `L`, recursion runs over the merged list
+    pub trait MergeInto<L, Is>: Base {
+        type Output: Base;
+
+        fn merge_into(self, list: L) -> Self::Output;
+    }
+
+    /// Base case
+    impl<L: Base> MergeInto<L, ()> for () {
+        type Output = L;
+
+        fn merge_into(self, list: L) -> Self::Output {
+            list
         }
     }
-}
 
+    /// Inductive step
+    impl<const INDEX: usize, L, H, U, I, Is> MergeInto<L, (I, Is)> for (H, Indexed<INDEX, U>)
+    where
+        H: MergeInto<L, Is>,
+        H::Output: HasIndex<INDEX>,
+        H::Output: MergeElem<Indexed<INDEX, U>, <H::Output as HasIndex<INDEX>>::Output, I>,
+        I: counters::Index,
+    {
+        type Output = <H::Output as MergeElem<Indexed<INDEX, U>, <H::Output as HasIndex<INDEX>>::Output, I>>::Output;
+
+        fn merge_into(self, list: L) -> Self::Output {
+            let (head, elem) = self;
+            head.merge_into(list).merge_elem(elem)
+        }
+    }
+
+    /// Union of two indexed LHLists
+    ///
+    /// Elements with the same index and type are kept only once,
+    /// the same index with different types fails compilation.
+    /// `Is` holds positions of the duplicates, and it is inferred.
+    pub trait Merge<R, Is>: Base {
+        type Output: Base;
+
+        fn merge(self, other: R) -> Self::Output;
+    }
+
+    impl<L: Base, R: MergeInto<L, Is>, Is> Merge<R, Is> for L {
+        type Output = R::Output;
+
+        fn merge(self, other: R) -> Self::Output {
+            other.merge_into(self)
+        }
+    }
+
+
+    // --------==========[ Index Validation ]==========--------
+
+    /// No element is indexed with `INDEX`
+    ///
+    /// Indices are compared as [`counters::Binary`] counters.
+    pub trait Lacks<const INDEX: usize>: Base { }
+
+    /// Base case
+    impl<const INDEX: usize> Lacks<INDEX> for () { }
+
+    /// Inductive step
+    impl<const INDEX: usize, const OTHER: usize, H, E> Lacks<INDEX> for (H, Indexed<OTHER, E>)
+    where
+        H: Lacks<INDEX>,
+        counters::Const<INDEX>: counters::IntoBinary,
+        counters::Const<OTHER>: counters::IntoBinary,
+        counters::Binary<INDEX>: counters::Eq<counters::Binary<OTHER>, Output = counters::False>,
+    { }
+
+    /// All indices are distinct
+    ///
+    /// ```
+    /// use hlist::indexed::{lhlist::Unique, Indexed};
+    ///
+    /// let list = (((), Indexed::<3, u8>::new(1)), Indexed::<700, u16>::new(2)).assert_unique();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use hlist::indexed::{lhlist::Unique, Indexed};
+    ///
+    /// let list = (((), Indexed::<3, u8>::new(1)), Indexed::<3, u16>::new(2)).assert_unique();
+    /// ```
+    pub trait Unique: Base {
+        /// Compiles only for lists with distinct indices
+        fn assert_unique(self) -> Self {
+            self
+        }
+    }
+
+    /// Base case
+    impl Unique for () { }
+
+    /// Inductive step
+    impl<const INDEX: usize, H, E> Unique for (H, Indexed<INDEX, E>)
+    where
+        H: Unique + Lacks<INDEX>,
+    { }
+
+    /// Indices form `0..LENGTH` in position order, counted with [`counters::Binary`] counters
+    ///
+    /// ```
+    /// use hlist::indexed::{lhlist::Contiguous, Indexed};
+    ///
+    /// let list = (((), Indexed::<0, u8>::new(1)), Indexed::<1, u16>::new(2)).assert_contiguous();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use hlist::indexed::{lhlist::Contiguous, Indexed};
+    ///
+    /// let list = (((), Indexed::<0, u8>::new(1)), Indexed::<2, u16>::new(2)).assert_contiguous();
+    /// ```
+    pub trait Contiguous: Base {
+        /// Index expected for the next element, as binary counter
+        type Next: counters::Increment;
+
+        /// Compiles only for lists indexed with `0..LENGTH` in position order
+        fn assert_contiguous(self) -> Self {
+            self
+        }
+    }
+
+    /// Base case
+    impl Contiguous for () {
+        type Next = counters::Zero;
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, H, E> Contiguous for (H, Indexed<INDEX, E>)
+    where
+        H: Contiguous,
+        H::Next: counters::ToConst<Const = counters::Const<INDEX>>,
+        <H::Next as counters::Increment>::Output: counters::Increment,
+    {
+        type Next = <H::Next as counters::Increment>::Output;
+    }
+}
+
+pub mod rhlist {
+    use super::*;
+
+    // TODO: Add Factory functions
+
+    // FIXME: Should we use `unordered_rhlist::Base` here?
+    pub trait Base: unordered_rhlist::Base { }
+    
+    impl Base for () { }
+    impl<const INDEX: usize, E, T: Base> Base for (Indexed<INDEX, E>, T) { }
+
+
+    pub trait Empty: Base {
+        fn default() -> Self;
+
+        fn prepend_indexed<E>(self, value: E) -> (Indexed<0, E>, Self);
+
+        fn new<E>(value: E) -> (Indexed<0, E>, Self);
+    }
+
+    impl Empty for () {
+        fn default() -> Self { }
+
+        fn new<E>(value: E) -> (Indexed<0, E>, Self) {
+            (Indexed::new(value), ())
+        }
+
+        fn prepend_indexed<E>(self, value: E) -> (Indexed<0, E>, Self) {
+            <Self as Empty>::new(value)
+        }
+    }
+
+    pub fn new() -> impl Empty {
+        <() as Empty>::default()
+    }
+
+
+    // --------==========[ Index HList Prepend ]==========--------
+    
+    /// Prepending new Indexed Element to RHList
+    pub trait Prepend<E>: Base {
+        fn prepend_indexed<const INDEX: usize>(self, value: E) -> (Indexed<INDEX, E>, Self);
+    }
+    
+    impl<H, T, E> Prepend<E> for (H, T)
+    where
+        (H, T): Base
+    {
+        fn prepend_indexed<const INDEX: usize>(self, value: E) -> (Indexed<INDEX, E>, Self) {
+            (Indexed::new(value), self)
+        }
+    }
+
+
+    // --------==========[ Index HList Append ]==========--------
+
+    /// Appending new Indexed Element at the tail of RHList
+    ///
+    /// Prepending used to be provided by this trait, it is kept as deprecated method.
+    pub trait Append<E>: Base {
+        type Appended<const INDEX: usize>: Base;
+
+        fn append_indexed<const INDEX: usize>(self, value: E) -> Self::Appended<INDEX>;
+
+        /// ```
+        /// # #![allow(deprecated)]
+        /// use hlist::indexed::{rhlist::Append, Indexed};
+        ///
+        /// let (head, _) = (Indexed::<0, u8>::new(1), ()).prepend_indexed::<1>(2u16);
+        /// assert_eq!(*head.get(), 2);
+        /// ```
+        #[deprecated(note = "prepending moved to `Prepend::prepend_indexed`")]
+        fn prepend_indexed<const INDEX: usize>(self, value: E) -> (Indexed<INDEX, E>, Self) {
+            (Indexed::new(value), self)
+        }
+    }
+
+    /// Base case
+    impl<E> Append<E> for () {
+        type Appended<const INDEX: usize> = (Indexed<INDEX, E>, ());
+
+        fn append_indexed<const INDEX: usize>(self, value: E) -> Self::Appended<INDEX> {
+            (Indexed::new(value), ())
+        }
+    }
+
+    /// Inductive step
+    impl<const I: usize, X, T: Append<E>, E> Append<E> for (Indexed<I, X>, T) {
+        type Appended<const INDEX: usize> = (Indexed<I, X>, T::Appended<INDEX>);
+
+        fn append_indexed<const INDEX: usize>(self, value: E) -> Self::Appended<INDEX> {
+            let (head, tail) = self;
+            (head, tail.append_indexed::<INDEX>(value))
+        }
+    }
+
+
+    // --------==========[ Index HList Append Next ]==========--------
+
+    /// Appending new element at the tail indexed with current length of the RHList
+    ///
+    /// Length is converted to binary counter, see [`counters::Binary`] for the limit.
+    pub trait AppendNext<E>: Base {
+        type Appended;
+
+        fn append_next(self, value: E) -> Self::Appended;
+    }
+
+    impl<L: Base, E> AppendNext<E> for L
+    where
+        <L as unordered_rhlist::Base>::Length: counters::IntoBinary,
+        NextIndex<L>: counters::ToConst,
+        <NextIndex<L> as counters::ToConst>::Const: Wrap,
+        L: unordered_rhlist::Append<Next<L, E>>,
+    {
+        type Appended = <L as unordered_rhlist::Append<Next<L, E>>>::Appended;
+
+        fn append_next(self, value: E) -> Self::Appended {
+            let indexed = <<NextIndex<L> as counters::ToConst>::Const as Wrap>::wrap(value);
+            unordered_rhlist::Append::append(self, indexed)
+        }
+    }
+
+    /// Length of the RHList as binary counter
+    type NextIndex<L> = <<L as unordered_rhlist::Base>::Length as counters::IntoBinary>::Binary;
+
+    /// Element appended by `AppendNext`
+    type Next<L, E> = IndexedAt<NextIndex<L>, E>;
+
+
+    // --------==========[ Inversion LHList <-> RHList ]==========--------
+
+    /// Indexed RHList conversion to indexed LHList
+    pub trait Invert: Base {
+        type Inverted: super::lhlist::Base;
+
+        fn invert(self) -> Self::Inverted;
+    }
+
+    /// Base case
+    impl Invert for () {
+        type Inverted = ();
+
+        fn invert(self) -> Self::Inverted {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, E, T: Invert> Invert for (Indexed<INDEX, E>, T)
+    where
+        T::Inverted: super::lhlist::Prepend<E>,
+    {
+        type Inverted = <T::Inverted as super::lhlist::Prepend<E>>::Prepended<INDEX>;
+
+        fn invert(self) -> Self::Inverted {
+            let (Indexed(elem), tail) = self;
+            super::lhlist::Prepend::prepend_indexed::<INDEX>(tail.invert(), elem)
+        }
+    }
+
+
+    // --------==========[ HList Reversion ]==========--------
+
+    /// Reverse indexed RHList
+    pub trait Reverse: Base {
+        type Reversed: Base;
+
+        fn reverse(self) -> Self::Reversed;
+    }
+
+    /// Base case
+    impl Reverse for () {
+        type Reversed = ();
+
+        fn reverse(self) -> Self::Reversed {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, E, T: Reverse> Reverse for (Indexed<INDEX, E>, T)
+    where
+        T::Reversed: Append<E>,
+    {
+        type Reversed = <T::Reversed as Append<E>>::Appended<INDEX>;
+
+        fn reverse(self) -> Self::Reversed {
+            let (Indexed(elem), tail) = self;
+            tail.reverse().append_indexed::<INDEX>(elem)
+        }
+    }
+
+
+    // --------==========[ Index Removal ]==========--------
+
+    /// Conversion to plain RHList by stripping `Indexed` wrappers
+    pub trait Unindex: Base {
+        type Unindexed: unordered_rhlist::Base;
+
+        fn unindex(self) -> Self::Unindexed;
+    }
+
+    /// Base case
+    impl Unindex for () {
+        type Unindexed = ();
+
+        fn unindex(self) -> Self::Unindexed {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, E, T: Unindex> Unindex for (Indexed<INDEX, E>, T) {
+        type Unindexed = (E, T::Unindexed);
+
+        fn unindex(self) -> Self::Unindexed {
+            let (Indexed(elem), tail) = self;
+            (elem, tail.unindex())
+        }
+    }
+
+
+    // --------==========[ Sorting ]==========--------
+
+    /// Insertion of element into RHList sorted by `INDEX`
+    ///
+    /// Indices are compared as [`counters::Binary`] counters.
+    pub trait Insert<X>: Base {
+        type Inserted: Base;
+
+        fn insert(self, elem: X) -> Self::Inserted;
+    }
+
+    /// Base case
+    impl<const INDEX: usize, E> Insert<Indexed<INDEX, E>> for () {
+        type Inserted = (Indexed<INDEX, E>, ());
+
+        fn insert(self, elem: Indexed<INDEX, E>) -> Self::Inserted {
+            (elem, ())
+        }
+    }
+
+    /// Inductive step, equal indices are rejected
+    impl<const INDEX: usize, const OTHER: usize, E, F, T> Insert<Indexed<INDEX, E>> for (Indexed<OTHER, F>, T)
+    where
+        (Indexed<OTHER, F>, T): InsertIf<Indexed<INDEX, E>, <counters::Binary<INDEX> as counters::Lt<counters::Binary<OTHER>>>::Output>,
+        counters::Const<INDEX>: counters::IntoBinary,
+        counters::Const<OTHER>: counters::IntoBinary,
+        counters::Binary<INDEX>: counters::Eq<counters::Binary<OTHER>, Output = counters::False>,
+        counters::Binary<INDEX>: counters::Lt<counters::Binary<OTHER>>,
+    {
+        type Inserted = <Self as InsertIf<Indexed<INDEX, E>, <counters::Binary<INDEX> as counters::Lt<counters::Binary<OTHER>>>::Output>>::Inserted;
+
+        fn insert(self, elem: Indexed<INDEX, E>) -> Self::Inserted {
+            self.insert_if(elem)
+        }
+    }
+
+    /// Insertion in front of RHList when `B` is `True`, further down otherwise
+    pub trait InsertIf<X, B: counters::Bool>: Base {
+        type Inserted: Base;
+
+        fn insert_if(self, elem: X) -> Self::Inserted;
+    }
+
+    impl<const INDEX: usize, E, L: Base> InsertIf<Indexed<INDEX, E>, counters::True> for L {
+        type Inserted = (Indexed<INDEX, E>, L);
+
+        fn insert_if(self, elem: Indexed<INDEX, E>) -> Self::Inserted {
+            (elem, self)
+        }
+    }
+
+    impl<X, const OTHER: usize, F, T: Insert<X>> InsertIf<X, counters::False> for (Indexed<OTHER, F>, T) {
+        type Inserted = (Indexed<OTHER, F>, T::Inserted);
+
+        fn insert_if(self, elem: X) -> Self::Inserted {
+            let (head, tail) = self;
+            (head, tail.insert(elem))
+        }
+    }
+
+    /// Ordering of elements by `INDEX`, duplicated indices fail to compile
+    ///
+    /// ```
+    /// use hlist::indexed::{rhlist::Sort, Indexed};
+    ///
+    /// let (a, (b, ())) = (Indexed::<900, u8>::new(1), (Indexed::<2, u16>::new(2), ())).sort();
+    /// assert_eq!((*a.get(), *b.get()), (2, 1));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use hlist::indexed::{rhlist::Sort, Indexed};
+    ///
+    /// let sorted = (Indexed::<2, u8>::new(1), (Indexed::<2, u16>::new(2), ())).sort();
+    /// ```
+    pub trait Sort: Base {
+        type Sorted: Base;
+
+        fn sort(self) -> Self::Sorted;
+    }
+
+    /// Base case
+    impl Sort for () {
+        type Sorted = ();
+
+        fn sort(self) -> Self::Sorted {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, E, T: Sort> Sort for (Indexed<INDEX, E>, T)
+    where
+        T::Sorted: Insert<Indexed<INDEX, E>>,
+    {
+        type Sorted = <T::Sorted as Insert<Indexed<INDEX, E>>>::Inserted;
+
+        fn sort(self) -> Self::Sorted {
+            let (elem, tail) = self;
+            tail.sort().insert(elem)
+        }
+    }
+
+
+    // --------==========[ Concatenation ]==========--------
+
+    /// Offsetting every index by counter `C`, unary or binary
+    ///
+    /// Shifted indices are computed as [`counters::Binary`] counters.
+    pub trait Shift<C: counters::Index>: Base {
+        type Shifted;
+
+        fn shift(self) -> Self::Shifted;
+    }
+
+    /// Base case
+    impl<C: counters::Index> Shift<C> for () {
+        type Shifted = ();
+
+        fn shift(self) -> Self::Shifted {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, E, T, C> Shift<C> for (Indexed<INDEX, E>, T)
+    where
+        T: Shift<C>,
+        C: counters::Index + counters::IntoBinary,
+        counters::Const<INDEX>: counters::IntoBinary,
+        counters::Binary<INDEX>: counters::Add<C::Binary>,
+        counters::Sum<counters::Binary<INDEX>, C::Binary>: counters::ToConst,
+        <counters::Sum<counters::Binary<INDEX>, C::Binary> as counters::ToConst>::Const: Wrap,
+    {
+        type Shifted = (IndexedAt<counters::Sum<counters::Binary<INDEX>, C::Binary>, E>, T::Shifted);
+
+        fn shift(self) -> Self::Shifted {
+            let (Indexed(elem), tail) = self;
+            let elem = <<counters::Sum<counters::Binary<INDEX>, C::Binary> as counters::ToConst>::Const as Wrap>::wrap(elem);
+            (elem, tail.shift())
+        }
+    }
+
+    /// Concatenation keeping indices of both lists unchanged
+    pub trait Chain<R>: Base {
+        type Chained: Base;
+
+        fn chain(self, rhs: R) -> Self::Chained;
+    }
+
+    /// Base case
+    impl<R: Base> Chain<R> for () {
+        type Chained = R;
+
+        fn chain(self, rhs: R) -> Self::Chained {
+            rhs
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, E, T, R> Chain<R> for (Indexed<INDEX, E>, T)
+    where
+        T: Chain<R>,
+    {
+        type Chained = (Indexed<INDEX, E>, T::Chained);
+
+        fn chain(self, rhs: R) -> Self::Chained {
+            let (elem, tail) = self;
+            (elem, tail.chain(rhs))
+        }
+    }
+
+    /// Concatenation with indices of `rhs` offset by length of `self`
+    pub trait Concat<R>: Base {
+        type Concatenated: Base;
+
+        fn concat(self, rhs: R) -> Self::Concatenated;
+    }
+
+    impl<L: Base, R> Concat<R> for L
+    where
+        R: Shift<<L as unordered_rhlist::Base>::Length>,
+        L: Chain<<R as Shift<<L as unordered_rhlist::Base>::Length>>::Shifted>,
+    {
+        type Concatenated = <L as Chain<<R as Shift<<L as unordered_rhlist::Base>::Length>>::Shifted>>::Chained;
+
+        fn concat(self, rhs: R) -> Self::Concatenated {
+            self.chain(rhs.shift())
+        }
+    }
+
+    /// Concatenation with indices of `rhs` offset by const `OFFSET`
+    pub trait ConcatOffset<R>: Base {
+        fn concat_offset<const OFFSET: usize>(self, rhs: R) -> <Self as Chain<<R as Shift<counters::Binary<OFFSET>>>::Shifted>>::Chained
+        where
+            counters::Const<OFFSET>: counters::IntoBinary,
+            R: Shift<counters::Binary<OFFSET>>,
+            Self: Chain<R::Shifted>;
+    }
+
+    impl<L: Base, R> ConcatOffset<R> for L {
+        fn concat_offset<const OFFSET: usize>(self, rhs: R) -> <Self as Chain<<R as Shift<counters::Binary<OFFSET>>>::Shifted>>::Chained
+        where
+            counters::Const<OFFSET>: counters::IntoBinary,
+            R: Shift<counters::Binary<OFFSET>>,
+            Self: Chain<R::Shifted>,
+        {
+            self.chain(rhs.shift())
+        }
+    }
+
+
+    // --------==========[ Index HList Selectors ]==========--------
+
+    /// Selection of element by its const `INDEX` regardless of its position
+    pub trait Selector<const INDEX: usize, Needle, I>: Base
+    where
+        I: counters::Index
+    {
+        fn select(&self) -> &Needle;
+
+        fn select_mut(&mut self) -> &mut Needle;
+    }
+
+    impl<const INDEX: usize, Needle, T: Base> Selector<INDEX, Needle, counters::Zero> for (Indexed<INDEX, Needle>, T) {
+        fn select(&self) -> &Needle {
+            &self.0.0
+        }
+
+        fn select_mut(&mut self) -> &mut Needle {
+            &mut self.0.0
+        }
+    }
+
+    impl<const INDEX: usize, H, T, Needle, I> Selector<INDEX, Needle, counters::Successor<I>> for (H, T)
+    where
+        T: Selector<INDEX, Needle, I>,
+        (H, T): Base,
+        I: counters::Index,
+    {
+        fn select(&self) -> &Needle {
+            self.1.select()
+        }
+
+        fn select_mut(&mut self) -> &mut Needle {
+            self.1.select_mut()
+        }
+    }
+
+    /// Getters by const `INDEX` with element type inferred, e.g. `list.get::<3>()`
+    pub trait IndexSelector<Needle, I>: Base {
+        fn get<const INDEX: usize>(&self) -> &Needle
+        where
+            Self: Selector<INDEX, Needle, I>,
+            I: counters::Index;
+
+        fn get_mut<const INDEX: usize>(&mut self) -> &mut Needle
+        where
+            Self: Selector<INDEX, Needle, I>,
+            I: counters::Index;
+    }
+
+    impl<L: Base, Needle, I> IndexSelector<Needle, I> for L {
+        fn get<const INDEX: usize>(&self) -> &Needle
+        where
+            Self: Selector<INDEX, Needle, I>,
+            I: counters::Index,
+        {
+            self.select()
+        }
+
+        fn get_mut<const INDEX: usize>(&mut self) -> &mut Needle
+        where
+            Self: Selector<INDEX, Needle, I>,
+            I: counters::Index,
+        {
+            self.select_mut()
+        }
+    }
+
+    // --------==========[ Index HList Removal ]==========--------
+
+    /// Removal of element by its const `INDEX` regardless of its position
+    pub trait Remover<const INDEX: usize, Needle, I>: Base
+    where
+        I: counters::Index
+    {
+        type Remainder: Base;
+
+        fn remove(self) -> (Needle, Self::Remainder);
+    }
+
+    /// Base case
+    impl<const INDEX: usize, Needle, T: Base> Remover<INDEX, Needle, counters::Zero> for (Indexed<INDEX, Needle>, T) {
+        type Remainder = T;
+
+        fn remove(self) -> (Needle, Self::Remainder) {
+            let (Indexed(elem), tail) = self;
+            (elem, tail)
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, const OTHER: usize, E, T, Needle, I> Remover<INDEX, Needle, counters::Successor<I>> for (Indexed<OTHER, E>, T)
+    where
+        T: Remover<INDEX, Needle, I>,
+        I: counters::Index,
+    {
+        type Remainder = (Indexed<OTHER, E>, T::Remainder);
+
+        fn remove(self) -> (Needle, Self::Remainder) {
+            let (elem, tail) = self;
+            let (needle, tail) = tail.remove();
+            (needle, (elem, tail))
+        }
+    }
+
+
+    // --------==========[ Runtime Index Selectors ]==========--------
+
+    /// Selection by `INDEX` known only at runtime
+    ///
+    /// Duplicated indices select the element first in position order.
+    ///
+    /// ```
+    /// use hlist::indexed::{rhlist::DynSelector, Indexed};
+    ///
+    /// let list = (Indexed::<1, u8>::new(1), (Indexed::<1, u16>::new(2), ()));
+    /// assert!(list.get_dyn(1).unwrap().is::<u8>());
+    /// ```
+    pub trait DynSelector: Base {
+        fn get_dyn(&self, index: usize) -> Option<&dyn Any>;
+
+        fn get_dyn_mut(&mut self, index: usize) -> Option<&mut dyn Any>;
+
+        fn get_as<T: Any>(&self, index: usize) -> Option<&T> {
+            self.get_dyn(index)?.downcast_ref()
+        }
+
+        fn get_as_mut<T: Any>(&mut self, index: usize) -> Option<&mut T> {
+            self.get_dyn_mut(index)?.downcast_mut()
+        }
+    }
+
+    /// Base case
+    impl DynSelector for () {
+        fn get_dyn(&self, _: usize) -> Option<&dyn Any> {
+            None
+        }
+
+        fn get_dyn_mut(&mut self, _: usize) -> Option<&mut dyn Any> {
+            None
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, E: Any, T: DynSelector> DynSelector for (Indexed<INDEX, E>, T) {
+        fn get_dyn(&self, index: usize) -> Option<&dyn Any> {
+            if index == INDEX {
+                Some(&self.0.0)
+            } else {
+                self.1.get_dyn(index)
+            }
+        }
+
+        fn get_dyn_mut(&mut self, index: usize) -> Option<&mut dyn Any> {
+            if index == INDEX {
+                Some(&mut self.0.0)
+            } else {
+                self.1.get_dyn_mut(index)
+            }
+        }
+    }
+
+
+    /// Type erased view of all elements paired with their `INDEX`, in position order
+    pub trait Entries: Base {
+        fn collect_entries<'a>(&'a self, entries: &mut Vec<(usize, &'a dyn Any)>);
+
+        fn entries(&self) -> Vec<(usize, &dyn Any)> {
+            let mut entries = Vec::with_capacity(Self::LENGTH);
+            self.collect_entries(&mut entries);
+            entries
+        }
+    }
+
+    /// Base case
+    impl Entries for () {
+        fn collect_entries<'a>(&'a self, _: &mut Vec<(usize, &'a dyn Any)>) { }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, E: Any, T: Entries> Entries for (Indexed<INDEX, E>, T) {
+        fn collect_entries<'a>(&'a self, entries: &mut Vec<(usize, &'a dyn Any)>) {
+            entries.push((INDEX, &self.0.0));
+            self.1.collect_entries(entries);
+        }
+    }
+
+    // --------==========[ Index Validation ]==========--------
+
+    /// No element is indexed with `INDEX`
+    ///
+    /// Indices are compared as [`counters::Binary`] counters.
+    pub trait Lacks<const INDEX: usize>: Base { }
+
+    /// Base case
+    impl<const INDEX: usize> Lacks<INDEX> for () { }
+
+    /// Inductive step
+    impl<const INDEX: usize, const OTHER: usize, E, T> Lacks<INDEX> for (Indexed<OTHER, E>, T)
+    where
+        T: Lacks<INDEX>,
+        counters::Const<INDEX>: counters::IntoBinary,
+        counters::Const<OTHER>: counters::IntoBinary,
+        counters::Binary<INDEX>: counters::Eq<counters::Binary<OTHER>, Output = counters::False>,
+    { }
+
+    /// All indices are distinct
+    ///
+    /// ```
+    /// use hlist::indexed::{rhlist::Unique, Indexed};
+    ///
+    /// let list = (Indexed::<3, u8>::new(1), (Indexed::<700, u16>::new(2), ())).assert_unique();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use hlist::indexed::{rhlist::Unique, Indexed};
+    ///
+    /// let list = (Indexed::<3, u8>::new(1), (Indexed::<3, u16>::new(2), ())).assert_unique();
+    /// ```
+    pub trait Unique: Base {
+        /// Compiles only for lists with distinct indices
+        fn assert_unique(self) -> Self {
+            self
+        }
+    }
+
+    /// Base case
+    impl Unique for () { }
+
+    /// Inductive step
+    impl<const INDEX: usize, E, T> Unique for (Indexed<INDEX, E>, T)
+    where
+        T: Unique + Lacks<INDEX>,
+    { }
+
+    /// Indices form `C..C + LENGTH` in position order, `C` is binary counter
+    pub trait ContiguousFrom<C: counters::Index>: Base { }
+
+    /// Base case
+    impl<C: counters::Index> ContiguousFrom<C> for () { }
+
+    /// Inductive step
+    impl<const INDEX: usize, E, T, C> ContiguousFrom<C> for (Indexed<INDEX, E>, T)
+    where
+        T: ContiguousFrom<C::Output>,
+        C: counters::Increment + counters::ToConst<Const = counters::Const<INDEX>>,
+    { }
+
+    /// Indices form `0..LENGTH` in position order, counted with [`counters::Binary`] counters
+    ///
+    /// ```
+    /// use hlist::indexed::{rhlist::Contiguous, Indexed};
+    ///
+    /// let list = (Indexed::<0, u8>::new(1), (Indexed::<1, u16>::new(2), ())).assert_contiguous();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use hlist::indexed::{rhlist::Contiguous, Indexed};
+    ///
+    /// let list = (Indexed::<1, u8>::new(1), (Indexed::<0, u16>::new(2), ())).assert_contiguous();
+    /// ```
+    pub trait Contiguous: Base {
+        /// Compiles only for lists indexed with `0..LENGTH` in position order
+        fn assert_contiguous(self) -> Self {
+            self
+        }
+    }
+
+    impl<L: ContiguousFrom<counters::Zero>> Contiguous for L { }
+}
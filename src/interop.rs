@@ -0,0 +1,184 @@
+// Uniform value traits for math library types, each behind its cargo feature.
+//
+// Types are handled as arrays they convert to, matrices as arrays of columns.
+
+use super::{
+    codegen::TypeName,
+    layout::{Layout, Rules},
+    uniform::{Uniform, UniformBackend},
+    vertex::{Attribute, VertexFormat},
+};
+
+
+// --------==========[ Array Delegation ]==========--------
+
+macro_rules! impl_via_array {
+    ($($ty:ty => $array:ty, |$value:ident| $to_array:expr;)*) => {
+        $(
+            impl Uniform for $ty {
+                fn upload<B: UniformBackend + ?Sized>(&self, location: usize, backend: &mut B) {
+                    let $value = self;
+                    <$array as Uniform>::upload(&$to_array, location, backend)
+                }
+            }
+
+            impl<R: Rules> Layout<R> for $ty {
+                const ALIGN: usize = <$array as Layout<R>>::ALIGN;
+                const SIZE: usize = <$array as Layout<R>>::SIZE;
+
+                fn write(&self, buf: &mut [u8]) {
+                    let $value = self;
+                    <$array as Layout<R>>::write(&$to_array, buf)
+                }
+            }
+
+            impl TypeName for $ty {
+                const GLSL: &'static str = <$array as TypeName>::GLSL;
+                const WGSL: &'static str = <$array as TypeName>::WGSL;
+            }
+        )*
+    };
+}
+
+macro_rules! impl_attribute_via_array {
+    ($($ty:ty => $array:ty, |$value:ident| $to_array:expr;)*) => {
+        $(
+            impl Attribute for $ty {
+                const FORMAT: VertexFormat = <$array as Attribute>::FORMAT;
+
+                fn write(&self, buf: &mut [u8]) {
+                    let $value = self;
+                    <$array as Attribute>::write(&$to_array, buf)
+                }
+            }
+        )*
+    };
+}
+
+
+// --------==========[ glam ]==========--------
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+    use super::*;
+    use glam::{IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4};
+
+    impl_via_array! {
+        Vec2 => [f32; 2], |v| v.to_array();
+        Vec3 => [f32; 3], |v| v.to_array();
+        Vec3A => [f32; 3], |v| v.to_array();
+        Vec4 => [f32; 4], |v| v.to_array();
+        IVec2 => [i32; 2], |v| v.to_array();
+        IVec3 => [i32; 3], |v| v.to_array();
+        IVec4 => [i32; 4], |v| v.to_array();
+        UVec2 => [u32; 2], |v| v.to_array();
+        UVec3 => [u32; 3], |v| v.to_array();
+        UVec4 => [u32; 4], |v| v.to_array();
+        Mat2 => [[f32; 2]; 2], |m| m.to_cols_array_2d();
+        Mat3 => [[f32; 3]; 3], |m| m.to_cols_array_2d();
+        Mat3A => [[f32; 3]; 3], |m| m.to_cols_array_2d();
+        Mat4 => [[f32; 4]; 4], |m| m.to_cols_array_2d();
+    }
+
+    impl_attribute_via_array! {
+        Vec2 => [f32; 2], |v| v.to_array();
+        Vec3 => [f32; 3], |v| v.to_array();
+        Vec3A => [f32; 3], |v| v.to_array();
+        Vec4 => [f32; 4], |v| v.to_array();
+        IVec2 => [i32; 2], |v| v.to_array();
+        IVec3 => [i32; 3], |v| v.to_array();
+        IVec4 => [i32; 4], |v| v.to_array();
+        UVec2 => [u32; 2], |v| v.to_array();
+        UVec3 => [u32; 3], |v| v.to_array();
+        UVec4 => [u32; 4], |v| v.to_array();
+    }
+}
+
+
+// --------==========[ mint ]==========--------
+
+#[cfg(feature = "mint")]
+mod mint_impls {
+    use super::*;
+    use mint::{ColumnMatrix2, ColumnMatrix3, ColumnMatrix4, Vector2, Vector3, Vector4};
+
+    impl_via_array! {
+        Vector2<f32> => [f32; 2], |v| <[f32; 2]>::from(*v);
+        Vector3<f32> => [f32; 3], |v| <[f32; 3]>::from(*v);
+        Vector4<f32> => [f32; 4], |v| <[f32; 4]>::from(*v);
+        Vector2<i32> => [i32; 2], |v| <[i32; 2]>::from(*v);
+        Vector3<i32> => [i32; 3], |v| <[i32; 3]>::from(*v);
+        Vector4<i32> => [i32; 4], |v| <[i32; 4]>::from(*v);
+        Vector2<u32> => [u32; 2], |v| <[u32; 2]>::from(*v);
+        Vector3<u32> => [u32; 3], |v| <[u32; 3]>::from(*v);
+        Vector4<u32> => [u32; 4], |v| <[u32; 4]>::from(*v);
+        ColumnMatrix2<f32> => [[f32; 2]; 2], |m| <[[f32; 2]; 2]>::from(*m);
+        ColumnMatrix3<f32> => [[f32; 3]; 3], |m| <[[f32; 3]; 3]>::from(*m);
+        ColumnMatrix4<f32> => [[f32; 4]; 4], |m| <[[f32; 4]; 4]>::from(*m);
+    }
+
+    impl_attribute_via_array! {
+        Vector2<f32> => [f32; 2], |v| <[f32; 2]>::from(*v);
+        Vector3<f32> => [f32; 3], |v| <[f32; 3]>::from(*v);
+        Vector4<f32> => [f32; 4], |v| <[f32; 4]>::from(*v);
+        Vector2<i32> => [i32; 2], |v| <[i32; 2]>::from(*v);
+        Vector3<i32> => [i32; 3], |v| <[i32; 3]>::from(*v);
+        Vector4<i32> => [i32; 4], |v| <[i32; 4]>::from(*v);
+        Vector2<u32> => [u32; 2], |v| <[u32; 2]>::from(*v);
+        Vector3<u32> => [u32; 3], |v| <[u32; 3]>::from(*v);
+        Vector4<u32> => [u32; 4], |v| <[u32; 4]>::from(*v);
+    }
+}
+
+
+#[cfg(all(test, feature = "glam"))]
+mod glam_tests {
+    use super::*;
+    use crate::layout::Std140;
+    use crate::uniform::{Call, RecordingBackend};
+    use glam::{Mat4, Vec3};
+
+    #[test]
+    fn mat4_uploads_columns() {
+        let matrix = Mat4::from_cols_array(&std::array::from_fn(|i| i as f32));
+        let mut backend = RecordingBackend::new();
+        matrix.upload(2, &mut backend);
+        assert_eq!(backend.calls, [
+            Call::Matrix { location: 2, columns: 4, rows: 4, value: (0..16).map(|i| i as f32).collect() },
+        ]);
+    }
+
+    #[test]
+    fn vec3_layout_and_names() {
+        assert_eq!((<Vec3 as Layout<Std140>>::ALIGN, <Vec3 as Layout<Std140>>::SIZE), (16, 12));
+        assert_eq!((Vec3::GLSL, Vec3::WGSL), ("vec3", "vec3<f32>"));
+        assert_eq!(Mat4::GLSL, "mat4");
+    }
+}
+
+
+#[cfg(all(test, feature = "mint"))]
+mod mint_tests {
+    use super::*;
+    use crate::layout::Std140;
+    use crate::uniform::{Call, RecordingBackend};
+    use mint::{ColumnMatrix4, Vector3};
+
+    #[test]
+    fn matrix4_uploads_columns() {
+        let matrix = ColumnMatrix4::<f32>::from(std::array::from_fn::<_, 4, _>(|c| std::array::from_fn(|r| (4 * c + r) as f32)));
+        let mut backend = RecordingBackend::new();
+        matrix.upload(2, &mut backend);
+        assert_eq!(backend.calls, [
+            Call::Matrix { location: 2, columns: 4, rows: 4, value: (0..16).map(|i| i as f32).collect() },
+        ]);
+    }
+
+    #[test]
+    fn vector3_layout_and_names() {
+        type Vec3 = Vector3<f32>;
+        assert_eq!((<Vec3 as Layout<Std140>>::ALIGN, <Vec3 as Layout<Std140>>::SIZE), (16, 12));
+        assert_eq!((Vec3::GLSL, Vec3::WGSL), ("vec3", "vec3<f32>"));
+        assert_eq!(ColumnMatrix4::<f32>::GLSL, "mat4");
+    }
+}
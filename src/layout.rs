@@ -0,0 +1,339 @@
+use super::codegen::Labelled;
+use super::indexed::Indexed;
+
+
+// --------==========[ Layout Rules ]==========--------
+
+/// Memory layout rules of GLSL interface blocks
+pub trait Rules {
+    /// Minimal alignment of structs, matrix columns and array elements
+    const MIN_ALIGN: usize;
+}
+
+/// Layout of uniform blocks, arrays and structs are aligned to `vec4`
+pub struct Std140;
+
+/// Layout of storage blocks, without `vec4` rounding
+pub struct Std430;
+
+impl Rules for Std140 {
+    const MIN_ALIGN: usize = 16;
+}
+
+impl Rules for Std430 {
+    const MIN_ALIGN: usize = 1;
+}
+
+/// Round `offset` up to multiple of `align`
+pub const fn align_up(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b { a } else { b }
+}
+
+
+// --------==========[ Layout Values ]==========--------
+
+/// Value with known alignment and size under layout rules `R`.
+pub trait Layout<R: Rules> {
+    const ALIGN: usize;
+    const SIZE: usize;
+
+    /// Write value into `buf` of exactly `SIZE` bytes
+    fn write(&self, buf: &mut [u8]);
+}
+
+macro_rules! impl_scalar_layout {
+    ($($ty:ty => |$value:ident| $bytes:expr),*) => {
+        $(
+            impl<R: Rules> Layout<R> for $ty {
+                const ALIGN: usize = 4;
+                const SIZE: usize = 4;
+
+                fn write(&self, buf: &mut [u8]) {
+                    let $value = *self;
+                    buf.copy_from_slice(&$bytes);
+                }
+            }
+
+            impl_scalar_layout!(@vector $ty; (2, 8) (3, 16) (4, 16));
+        )*
+    };
+    (@vector $ty:ty; $(($n:literal, $align:literal))*) => {
+        $(
+            impl<R: Rules> Layout<R> for [$ty; $n] {
+                const ALIGN: usize = $align;
+                const SIZE: usize = 4 * $n;
+
+                fn write(&self, buf: &mut [u8]) {
+                    for (value, buf) in self.iter().zip(buf.chunks_exact_mut(4)) {
+                        <$ty as Layout<R>>::write(value, buf);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_layout!(
+    bool => |value| (value as u32).to_le_bytes(),
+    i32 => |value| value.to_le_bytes(),
+    u32 => |value| value.to_le_bytes(),
+    f32 => |value| value.to_le_bytes()
+);
+
+/// Matrix as array of columns, each column aligned like array element
+impl<R: Rules, const ROWS: usize, const COLUMNS: usize> Layout<R> for [[f32; ROWS]; COLUMNS]
+where
+    [f32; ROWS]: Layout<R>,
+{
+    const ALIGN: usize = align_up(<[f32; ROWS] as Layout<R>>::ALIGN, R::MIN_ALIGN);
+    const SIZE: usize = COLUMNS * <Self as Layout<R>>::ALIGN;
+
+    fn write(&self, buf: &mut [u8]) {
+        let stride = <Self as Layout<R>>::ALIGN;
+        for (column, buf) in self.iter().zip(buf.chunks_exact_mut(stride)) {
+            column.write(&mut buf[..4 * ROWS]);
+        }
+    }
+}
+
+impl<R: Rules, const INDEX: usize, T: Layout<R>> Layout<R> for Indexed<INDEX, T> {
+    const ALIGN: usize = T::ALIGN;
+    const SIZE: usize = T::SIZE;
+
+    fn write(&self, buf: &mut [u8]) {
+        self.get().write(buf)
+    }
+}
+
+impl<R: Rules, T: Layout<R>> Layout<R> for Labelled<T> {
+    const ALIGN: usize = T::ALIGN;
+    const SIZE: usize = T::SIZE;
+
+    fn write(&self, buf: &mut [u8]) {
+        self.value.write(buf)
+    }
+}
+
+
+// --------==========[ Fields ]==========--------
+
+/// Placement of single element in a block
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Field {
+    pub offset: usize,
+    pub size: usize,
+    pub align: usize,
+    /// Padding inserted before the element
+    pub padding: usize,
+}
+
+
+pub mod lhlist {
+    use super::*;
+    use crate::lhlist::Base;
+
+
+    // --------==========[ Block Layout ]==========--------
+
+    /// LHList laid out as struct under layout rules `R`
+    pub trait Block<R: Rules>: Base {
+        const ALIGN: usize;
+        /// End of the last element
+        const END: usize;
+        /// Offset of the last element
+        const OFFSET: usize;
+        /// Size including trailing padding
+        const SIZE: usize = align_up(Self::END, Self::ALIGN);
+
+        fn collect_fields(fields: &mut Vec<Field>);
+
+        /// Write elements into `buf` of at least `SIZE` bytes, padding is left untouched
+        fn write_block(&self, buf: &mut [u8]);
+
+        fn fields() -> Vec<Field> {
+            let mut fields = Vec::with_capacity(Self::LENGTH);
+            Self::collect_fields(&mut fields);
+            fields
+        }
+
+        fn to_bytes(&self) -> Vec<u8> {
+            let mut buf = vec![0; Self::SIZE];
+            self.write_block(&mut buf);
+            buf
+        }
+    }
+
+    /// Base case
+    impl<R: Rules> Block<R> for () {
+        const ALIGN: usize = R::MIN_ALIGN;
+        const END: usize = 0;
+        const OFFSET: usize = 0;
+
+        fn collect_fields(_: &mut Vec<Field>) { }
+
+        fn write_block(&self, _: &mut [u8]) { }
+    }
+
+    /// Inductive step
+    impl<R: Rules, H: Block<R>, E: Layout<R>> Block<R> for (H, E) {
+        const ALIGN: usize = max(H::ALIGN, E::ALIGN);
+        const END: usize = <Self as Block<R>>::OFFSET + E::SIZE;
+        const OFFSET: usize = align_up(H::END, E::ALIGN);
+
+        fn collect_fields(fields: &mut Vec<Field>) {
+            H::collect_fields(fields);
+            let offset = <Self as Block<R>>::OFFSET;
+            fields.push(Field {
+                offset,
+                size: E::SIZE,
+                align: E::ALIGN,
+                padding: offset - H::END,
+            });
+        }
+
+        fn write_block(&self, buf: &mut [u8]) {
+            self.0.write_block(buf);
+            self.1.write(&mut buf[<Self as Block<R>>::OFFSET..<Self as Block<R>>::END]);
+        }
+    }
+}
+
+
+pub mod rhlist {
+    use super::*;
+    use crate::rhlist::{Base, Invert};
+
+
+    // --------==========[ Block Layout ]==========--------
+
+    /// Writing of RHList elements starting at runtime offset
+    pub trait WriteFrom<R: Rules>: Base {
+        fn write_from(&self, offset: usize, buf: &mut [u8]);
+    }
+
+    /// Base case
+    impl<R: Rules> WriteFrom<R> for () {
+        fn write_from(&self, _: usize, _: &mut [u8]) { }
+    }
+
+    /// Inductive step
+    impl<R: Rules, E: Layout<R>, T: WriteFrom<R>> WriteFrom<R> for (E, T) {
+        fn write_from(&self, offset: usize, buf: &mut [u8]) {
+            let offset = align_up(offset, E::ALIGN);
+            self.0.write(&mut buf[offset..offset + E::SIZE]);
+            self.1.write_from(offset + E::SIZE, buf);
+        }
+    }
+
+    /// RHList laid out as struct under layout rules `R`
+    ///
+    /// Offsets depend on preceding elements,
+    /// so they are computed on the inverted LHList.
+    pub trait Block<R: Rules>: WriteFrom<R> {
+        const ALIGN: usize;
+        /// Size including trailing padding
+        const SIZE: usize;
+
+        fn fields() -> Vec<Field>;
+
+        /// Write elements into `buf` of at least `SIZE` bytes, padding is left untouched
+        fn write_block(&self, buf: &mut [u8]) {
+            self.write_from(0, buf)
+        }
+
+        fn to_bytes(&self) -> Vec<u8> {
+            let mut buf = vec![0; Self::SIZE];
+            self.write_block(&mut buf);
+            buf
+        }
+    }
+
+    impl<R: Rules, L> Block<R> for L
+    where
+        L: WriteFrom<R> + Invert,
+        L::Inverted: super::lhlist::Block<R>,
+    {
+        const ALIGN: usize = <L::Inverted as super::lhlist::Block<R>>::ALIGN;
+        const SIZE: usize = <L::Inverted as super::lhlist::Block<R>>::SIZE;
+
+        fn fields() -> Vec<Field> {
+            <L::Inverted as super::lhlist::Block<R>>::fields()
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(offset: usize, size: usize, align: usize, padding: usize) -> Field {
+        Field { offset, size, align, padding }
+    }
+
+    type Block = (((((), [f32; 3]), f32), [[f32; 3]; 3]), [[f32; 2]; 2]);
+
+    #[test]
+    fn std140_fields() {
+        assert_eq!(<Block as lhlist::Block<Std140>>::fields(), [
+            field(0, 12, 16, 0),
+            // float fills the tail of vec3
+            field(12, 4, 4, 0),
+            // mat3 columns are padded to vec4
+            field(16, 48, 16, 0),
+            // mat2 columns are padded to vec4 too
+            field(64, 32, 16, 0),
+        ]);
+        assert_eq!(<Block as lhlist::Block<Std140>>::SIZE, 96);
+    }
+
+    #[test]
+    fn std430_fields() {
+        assert_eq!(<Block as lhlist::Block<Std430>>::fields(), [
+            field(0, 12, 16, 0),
+            field(12, 4, 4, 0),
+            // vec3 columns keep vec4 alignment
+            field(16, 48, 16, 0),
+            // vec2 columns are packed
+            field(64, 16, 8, 0),
+        ]);
+        assert_eq!(<Block as lhlist::Block<Std430>>::SIZE, 80);
+    }
+
+    #[test]
+    fn padding_and_struct_size() {
+        type Padded = ((((), f32), [f32; 2]), f32);
+
+        assert_eq!(<Padded as lhlist::Block<Std140>>::fields(), [
+            field(0, 4, 4, 0),
+            field(8, 8, 8, 4),
+            field(16, 4, 4, 0),
+        ]);
+        // std140 rounds struct size up to vec4, std430 to the largest member
+        assert_eq!(<Padded as lhlist::Block<Std140>>::SIZE, 32);
+        assert_eq!(<Padded as lhlist::Block<Std430>>::SIZE, 24);
+    }
+
+    #[test]
+    fn written_bytes() {
+        let block = ((((), [1.0f32, 2.0, 3.0]), 4.0f32), [[5.0f32, 6.0], [7.0, 8.0]]);
+        let bytes = lhlist::Block::<Std140>::to_bytes(&block);
+        let floats: Vec<f32> = bytes
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        assert_eq!(floats, [
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 0.0, 0.0,
+            7.0, 8.0, 0.0, 0.0,
+        ]);
+
+        let rhlist = ([1.0f32, 2.0, 3.0], (4.0f32, ([[5.0f32, 6.0], [7.0, 8.0]], ())));
+        assert_eq!(rhlist::Block::<Std140>::to_bytes(&rhlist), bytes);
+        assert_eq!(lhlist::Block::<Std430>::to_bytes(&block).len(), 32);
+    }
+}
@@ -1,15 +1,27 @@
 
 mod common;
+#[cfg(any(feature = "glam", feature = "mint"))]
+mod interop;
 mod test;
 
+pub mod codegen;
+pub mod coproduct;
 pub mod counters;
+pub mod dirty;
+pub mod dispatch;
 pub mod indexed;
+pub mod layout;
+pub mod registry;
+pub mod shader;
+pub mod uniform;
+pub mod validation;
+pub mod vertex;
 
 use crate::common::Disjoint;
 
 pub mod lhlist {
     #[allow(unused)]
-    use super::{counters, Disjoint, Left};
+    use super::{counters, indexed, Disjoint, Left};
 
     
     // --------==========[ Base Traits ]==========--------
@@ -17,6 +29,9 @@ pub mod lhlist {
     /// Left folded HList
     pub trait Base: Sized {
         const LENGTH: usize;
+
+        /// Length as type level counter
+        type Length: counters::Index;
         
         fn append<T>(self, t: T) -> (Self, T) {
             (self, t)
@@ -25,10 +40,12 @@ pub mod lhlist {
        
     impl Base for () {
         const LENGTH: usize = 0;
+        type Length = counters::Zero;
     }
     
     impl<T: Base, H> Base for (T, H) {
         const LENGTH: usize = T::LENGTH + 1;
+        type Length = counters::Successor<T::Length>;
     }
 
 
@@ -39,9 +56,7 @@ pub mod lhlist {
     }
     
     impl Empty for () {
-        fn default() -> Self {
-            ()
-        }
+        fn default() -> Self { }
     }
 
 
@@ -227,6 +242,51 @@ pub mod lhlist {
     }
 
 
+    // --------==========[ Enumeration ]==========--------
+
+    /// Conversion to indexed LHList, each element indexed with its position from the front
+    ///
+    /// Positions are binary counters, see [`counters::Binary`] for the limit.
+    ///
+    /// ```
+    /// use hlist::indexed::{lhlist::Unindex, Indexed};
+    /// use hlist::lhlist::Enumerate;
+    ///
+    /// let list = ((((), 1u8), 2u16), 3u32);
+    /// let (_, c): (_, Indexed<2, u32>) = list.enumerate();
+    /// assert_eq!(c.get(), &3);
+    /// assert_eq!(list.enumerate().unindex(), list);
+    /// ```
+    pub trait Enumerate: Base {
+        type Enumerated: indexed::lhlist::Base;
+
+        fn enumerate(self) -> Self::Enumerated;
+    }
+
+    /// Base case
+    impl Enumerate for () {
+        type Enumerated = ();
+
+        fn enumerate(self) -> Self::Enumerated {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, H: Enumerate, E> Enumerate for (H, E)
+    where
+        H::Length: counters::IntoBinary,
+        <H::Length as counters::IntoBinary>::Binary: counters::ToConst<Const = counters::Const<INDEX>>,
+    {
+        type Enumerated = (H::Enumerated, indexed::Indexed<INDEX, E>);
+
+        fn enumerate(self) -> Self::Enumerated {
+            let (head, elem) = self;
+            (head.enumerate(), indexed::Indexed::new(elem))
+        }
+    }
+
+
     // --------==========[ HList Selectors ]==========--------
     
     pub trait Selector<Needle, I>: Base
@@ -262,10 +322,135 @@ pub mod lhlist {
         }
     }
 
+
+    // --------==========[ HList Removal ]==========--------
+
+    /// Removal of element located by its type, like in `Selector`
+    pub trait Remover<Needle, I>: Base
+    where
+        I: counters::Index
+    {
+        type Remainder: Base;
+
+        fn remove(self) -> (Needle, Self::Remainder);
+    }
+
+    /// Base case
+    impl<H: Base, Needle> Remover<Needle, counters::Zero> for (H, Needle) {
+        type Remainder = H;
+
+        fn remove(self) -> (Needle, Self::Remainder) {
+            (self.1, self.0)
+        }
+    }
+
+    /// Inductive step
+    impl<H, T, Needle, I> Remover<Needle, counters::Successor<I>> for (H, T)
+    where
+        H: Remover<Needle, I>,
+        I: counters::Index,
+    {
+        type Remainder = (H::Remainder, T);
+
+        fn remove(self) -> (Needle, Self::Remainder) {
+            let (head, elem) = self;
+            let (needle, head) = head.remove();
+            (needle, (head, elem))
+        }
+    }
+
+
+    // --------==========[ Positional Selectors ]==========--------
+
+    /// Selection by explicit index, counted the same way as in `Selector`
+    ///
+    /// Index may be unary or binary counter, see [`counters::Decrement`].
+    pub trait At<I: counters::Index>: Base {
+        type Output;
+
+        fn at(&self) -> &Self::Output;
+
+        fn at_mut(&mut self) -> &mut Self::Output;
+    }
+
+    /// Base case
+    impl<H: Base, E> At<counters::Zero> for (H, E) {
+        type Output = E;
+
+        fn at(&self) -> &Self::Output {
+            &self.1
+        }
+
+        fn at_mut(&mut self) -> &mut Self::Output {
+            &mut self.1
+        }
+    }
+
+    /// Inductive step
+    impl<H, T, I> At<I> for (H, T)
+    where
+        I: counters::Decrement,
+        H: At<I::Output>,
+        (H, T): Base,
+    {
+        type Output = <H as At<I::Output>>::Output;
+
+        fn at(&self) -> &Self::Output {
+            self.0.at()
+        }
+
+        fn at_mut(&mut self) -> &mut Self::Output {
+            self.0.at_mut()
+        }
+    }
+
+
+    // --------==========[ Const Index Selectors ]==========--------
+
+    /// Selection by const generic index, counted the same way as in `Selector`
+    ///
+    /// ```
+    /// use hlist::lhlist::ConstSelector;
+    ///
+    /// let mut list = ((((), 1u8), "two"), 3.0f32);
+    /// assert_eq!(list.get_at::<0>(), &3.0);
+    /// assert_eq!(list.get_at::<2>(), &1);
+    /// *list.get_at_mut::<1>() = "changed";
+    /// assert_eq!(list.get_at::<1>(), &"changed");
+    /// ```
+    pub trait ConstSelector: Base {
+        fn get_at<const N: usize>(&self) -> &<Self as At<counters::Binary<N>>>::Output
+        where
+            counters::Const<N>: counters::IntoBinary,
+            Self: At<counters::Binary<N>>;
+
+        fn get_at_mut<const N: usize>(&mut self) -> &mut <Self as At<counters::Binary<N>>>::Output
+        where
+            counters::Const<N>: counters::IntoBinary,
+            Self: At<counters::Binary<N>>;
+    }
+
+    impl<L: Base> ConstSelector for L {
+        fn get_at<const N: usize>(&self) -> &<Self as At<counters::Binary<N>>>::Output
+        where
+            counters::Const<N>: counters::IntoBinary,
+            Self: At<counters::Binary<N>>,
+        {
+            self.at()
+        }
+
+        fn get_at_mut<const N: usize>(&mut self) -> &mut <Self as At<counters::Binary<N>>>::Output
+        where
+            counters::Const<N>: counters::IntoBinary,
+            Self: At<counters::Binary<N>>,
+        {
+            self.at_mut()
+        }
+    }
 }
 
 pub mod rhlist {
-    use super::counters;
+    use super::{counters, indexed};
 
 
     // --------==========[ Base Traits ]==========--------
@@ -273,6 +458,9 @@ pub mod rhlist {
     pub trait Base: Sized {
         const LENGTH: usize;
 
+        /// Length as type level counter
+        type Length: counters::Index;
+
         fn prepend<H>(self, h: H) -> (H, Self) {
             (h, self)
         }
@@ -280,10 +468,12 @@ pub mod rhlist {
 
     impl Base for () {
         const LENGTH: usize = 0;
+        type Length = counters::Zero;
     }
 
     impl<H, T: Base> Base for (H, T) {
         const LENGTH: usize = T::LENGTH + 1;
+        type Length = counters::Successor<T::Length>;
     }
 
 
@@ -294,9 +484,7 @@ pub mod rhlist {
     }
 
     impl Empty for () {
-        fn default() -> Self {
-            ()
-        }
+        fn default() -> Self { }
     }
 
 
@@ -432,9 +620,7 @@ pub mod rhlist {
     impl Invert for () {
         type Inverted = ();
 
-        fn invert(self) -> Self::Inverted {
-            ()
-        }
+        fn invert(self) -> Self::Inverted { }
     }
 
     /// Inductive step
@@ -483,6 +669,66 @@ pub mod rhlist {
     }
 
 
+    // --------==========[ Enumeration ]==========--------
+
+    /// Conversion to indexed RHList, each element indexed with its position offset by binary counter `C`
+    pub trait EnumerateFrom<C: counters::Index>: Base {
+        type Enumerated: indexed::rhlist::Base;
+
+        fn enumerate_from(self) -> Self::Enumerated;
+    }
+
+    /// Base case
+    impl<C: counters::Index> EnumerateFrom<C> for () {
+        type Enumerated = ();
+
+        fn enumerate_from(self) -> Self::Enumerated {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<const INDEX: usize, E, T, C> EnumerateFrom<C> for (E, T)
+    where
+        T: EnumerateFrom<C::Output>,
+        C: counters::Increment + counters::ToConst<Const = counters::Const<INDEX>>,
+    {
+        type Enumerated = (indexed::Indexed<INDEX, E>, T::Enumerated);
+
+        fn enumerate_from(self) -> Self::Enumerated {
+            let (elem, tail) = self;
+            (indexed::Indexed::new(elem), tail.enumerate_from())
+        }
+    }
+
+    /// Conversion to indexed RHList, each element indexed with its position from the front
+    ///
+    /// Positions are binary counters, see [`counters::Binary`] for the limit.
+    ///
+    /// ```
+    /// use hlist::indexed::{rhlist::Unindex, Indexed};
+    /// use hlist::rhlist::Enumerate;
+    ///
+    /// let list = (1u8, (2u16, (3u32, ())));
+    /// let (_, (_, (c, ()))): (_, (_, (Indexed<2, u32>, ()))) = list.enumerate();
+    /// assert_eq!(c.get(), &3);
+    /// assert_eq!(list.enumerate().unindex(), list);
+    /// ```
+    pub trait Enumerate: Base {
+        type Enumerated: indexed::rhlist::Base;
+
+        fn enumerate(self) -> Self::Enumerated;
+    }
+
+    impl<L: EnumerateFrom<counters::Zero>> Enumerate for L {
+        type Enumerated = L::Enumerated;
+
+        fn enumerate(self) -> Self::Enumerated {
+            self.enumerate_from()
+        }
+    }
+
+
     // --------==========[ HList Selectors ]==========--------
 
     /// Selection for RHList
@@ -518,6 +764,132 @@ pub mod rhlist {
             self.1.get_mut()
         }
     }
+
+
+    // --------==========[ HList Removal ]==========--------
+
+    /// Removal of element located by its type, like in `Selector`
+    pub trait Remover<Needle, I>: Base
+    where
+        I: counters::Index
+    {
+        type Remainder: Base;
+
+        fn remove(self) -> (Needle, Self::Remainder);
+    }
+
+    /// Base case
+    impl<Needle, T: Base> Remover<Needle, counters::Zero> for (Needle, T) {
+        type Remainder = T;
+
+        fn remove(self) -> (Needle, Self::Remainder) {
+            self
+        }
+    }
+
+    /// Inductive step
+    impl<H, T, Needle, I> Remover<Needle, counters::Successor<I>> for (H, T)
+    where
+        T: Remover<Needle, I>,
+        I: counters::Index,
+    {
+        type Remainder = (H, T::Remainder);
+
+        fn remove(self) -> (Needle, Self::Remainder) {
+            let (elem, tail) = self;
+            let (needle, tail) = tail.remove();
+            (needle, (elem, tail))
+        }
+    }
+
+
+    // --------==========[ Positional Selectors ]==========--------
+
+    /// Selection by explicit index, counted the same way as in `Selector`
+    ///
+    /// Index may be unary or binary counter, see [`counters::Decrement`].
+    pub trait At<I: counters::Index>: Base {
+        type Output;
+
+        fn at(&self) -> &Self::Output;
+
+        fn at_mut(&mut self) -> &mut Self::Output;
+    }
+
+    /// Base case
+    impl<E, T: Base> At<counters::Zero> for (E, T) {
+        type Output = E;
+
+        fn at(&self) -> &Self::Output {
+            &self.0
+        }
+
+        fn at_mut(&mut self) -> &mut Self::Output {
+            &mut self.0
+        }
+    }
+
+    /// Inductive step
+    impl<H, T, I> At<I> for (H, T)
+    where
+        I: counters::Decrement,
+        T: At<I::Output>,
+        (H, T): Base,
+    {
+        type Output = <T as At<I::Output>>::Output;
+
+        fn at(&self) -> &Self::Output {
+            self.1.at()
+        }
+
+        fn at_mut(&mut self) -> &mut Self::Output {
+            self.1.at_mut()
+        }
+    }
+
+
+    // --------==========[ Const Index Selectors ]==========--------
+
+    /// Selection by const generic index, counted the same way as in `Selector`
+    ///
+    /// ```
+    /// use hlist::rhlist::ConstSelector;
+    ///
+    /// let mut list = (1u8, ("two", (3.0f32, ())));
+    /// assert_eq!(list.get_at::<0>(), &1);
+    /// assert_eq!(list.get_at::<2>(), &3.0);
+    /// *list.get_at_mut::<1>() = "changed";
+    /// assert_eq!(list.get_at::<1>(), &"changed");
+    /// ```
+    pub trait ConstSelector: Base {
+        fn get_at<const N: usize>(&self) -> &<Self as At<counters::Binary<N>>>::Output
+        where
+            counters::Const<N>: counters::IntoBinary,
+            Self: At<counters::Binary<N>>;
+
+        fn get_at_mut<const N: usize>(&mut self) -> &mut <Self as At<counters::Binary<N>>>::Output
+        where
+            counters::Const<N>: counters::IntoBinary,
+            Self: At<counters::Binary<N>>;
+    }
+
+    impl<L: Base> ConstSelector for L {
+        fn get_at<const N: usize>(&self) -> &<Self as At<counters::Binary<N>>>::Output
+        where
+            counters::Const<N>: counters::IntoBinary,
+            Self: At<counters::Binary<N>>,
+        {
+            self.at()
+        }
+
+        fn get_at_mut<const N: usize>(&mut self) -> &mut <Self as At<counters::Binary<N>>>::Output
+        where
+            counters::Const<N>: counters::IntoBinary,
+            Self: At<counters::Binary<N>>,
+        {
+            self.at_mut()
+        }
+    }
 }
 
 
@@ -597,6 +969,7 @@ mod private {
     //     // TODO: Implement HList interface using HListHelper
     // }
     
+    #[allow(dead_code)]
     trait HListHelper<Type> {
         type Prepended;
         type Appended;
@@ -0,0 +1,103 @@
+use super::counters;
+use super::indexed::{lhlist, Indexed};
+
+
+// --------==========[ Registry ]==========--------
+
+/// Values kept together with their const indices, e.g. uniform locations.
+///
+/// Values are stored in LHList of `Indexed` in order of addition,
+/// each `INDEX` may occur only once which is checked at compile time.
+#[derive(Default)]
+pub struct Registry<US = ()> {
+    uniforms: US,
+}
+
+impl Registry<()> {
+    pub fn new() -> Self {
+        Self { uniforms: () }
+    }
+}
+
+impl<US: lhlist::Base> Registry<US> {
+    /// Add value under `INDEX`, adding already present `INDEX` fails compilation.
+    ///
+    /// Indices are checked by `indexed::lhlist::Lacks`, limited by [`counters::Binary`].
+    ///
+    /// ```
+    /// use hlist::registry::Registry;
+    ///
+    /// let registry = Registry::new().add::<3, f32>(1.0).add::<200, u32>(2);
+    /// assert_eq!(*registry.get::<200, u32, _>(), 2);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use hlist::registry::Registry;
+    ///
+    /// let registry = Registry::new().add::<3, f32>(1.0).add::<3, u32>(2);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<const INDEX: usize, U>(self, value: U) -> Registry<(US, Indexed<INDEX, U>)>
+    where
+        US: lhlist::Lacks<INDEX>,
+    {
+        Registry { uniforms: (self.uniforms, Indexed::new(value)) }
+    }
+
+    pub fn get<const INDEX: usize, U, I>(&self) -> &U
+    where
+        US: lhlist::Selector<INDEX, U, I>,
+        I: counters::Index,
+    {
+        self.uniforms.select()
+    }
+
+    pub fn get_mut<const INDEX: usize, U, I>(&mut self) -> &mut U
+    where
+        US: lhlist::Selector<INDEX, U, I>,
+        I: counters::Index,
+    {
+        self.uniforms.select_mut()
+    }
+
+    /// Replace value under `INDEX`, returns the previous one.
+    pub fn set<const INDEX: usize, U, I>(&mut self, value: U) -> U
+    where
+        US: lhlist::Selector<INDEX, U, I>,
+        I: counters::Index,
+    {
+        std::mem::replace(self.uniforms.select_mut(), value)
+    }
+
+    /// Remove value under `INDEX`, returns it together with the rest of Registry.
+    pub fn remove<const INDEX: usize, U, I>(self) -> (U, Registry<US::Remainder>)
+    where
+        US: lhlist::Remover<INDEX, U, I>,
+        I: counters::Index,
+    {
+        let (value, uniforms) = self.uniforms.remove();
+        (value, Registry { uniforms })
+    }
+
+    /// Type erased entries sorted by their index.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &dyn std::any::Any)>
+    where
+        US: lhlist::Entries,
+    {
+        let mut entries = self.uniforms.entries();
+        entries.sort_by_key(|(index, _)| *index);
+        entries.into_iter()
+    }
+
+    pub fn len(&self) -> usize {
+        US::LENGTH
+    }
+
+    pub fn is_empty(&self) -> bool {
+        US::LENGTH == 0
+    }
+
+    pub fn into_inner(self) -> US {
+        self.uniforms
+    }
+}
@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+
+use super::{counters, indexed, lhlist};
+
+
+// --------==========[ Shader ]==========--------
+
+/// Shader together with values of all its uniforms.
+///
+/// `US` is LHList of uniforms, usually `indexed::Indexed` by their location.
+pub struct Shader<US> {
+    uniforms: US,
+}
+
+impl<US: lhlist::Base> Shader<US> {
+    pub fn uniforms(&self) -> &US {
+        &self.uniforms
+    }
+
+    pub fn uniforms_mut(&mut self) -> &mut US {
+        &mut self.uniforms
+    }
+
+    pub fn into_uniforms(self) -> US {
+        self.uniforms
+    }
+
+    /// Link with next stage, uniforms shared by both are kept once.
+    ///
+    /// Stages using the same location for different types fail compilation.
+    ///
+    /// ```
+    /// use hlist::indexed::Indexed;
+    /// use hlist::shader::Builder;
+    ///
+    /// let vertex = Builder::<((), Indexed<300, f32>), ()>::new().set(Indexed::<300, f32>::new(1.0)).build();
+    /// let fragment = Builder::<((), Indexed<300, f32>), ()>::new().set(Indexed::<300, f32>::new(1.0)).build();
+    /// let ((), _): ((), Indexed<300, f32>) = vertex.link(fragment).into_uniforms();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use hlist::indexed::Indexed;
+    /// use hlist::shader::Builder;
+    ///
+    /// let vertex = Builder::<((), Indexed<300, f32>), ()>::new().set(Indexed::<300, f32>::new(1.0)).build();
+    /// let fragment = Builder::<((), Indexed<300, u32>), ()>::new().set(Indexed::<300, u32>::new(1)).build();
+    /// let program = vertex.link(fragment);
+    /// ```
+    pub fn link<SS, Is>(self, stage: Shader<SS>) -> Program<US::Output>
+    where
+        US: indexed::lhlist::Merge<SS, Is>,
+        SS: lhlist::Base,
+    {
+        Program { uniforms: self.uniforms.merge(stage.uniforms) }
+    }
+}
+
+
+// --------==========[ Builder ]==========--------
+
+/// Declared uniforms which were not set yet, holds no values.
+pub struct UnInitUniforms<US>(PhantomData<US>);
+
+/// Uniforms which were set, in order of setting.
+pub struct InitUniforms<US>(US);
+
+/// Typestate builder of `Shader`.
+///
+/// Each `set` moves uniform from `UUS` to `IUS`,
+/// `build` is available only once `UUS` is empty.
+/// Built `Shader` holds uniforms in order of declared `DUS`, not in order of setting.
+///
+/// ```
+/// use hlist::indexed::Indexed;
+/// use hlist::shader::{Builder, Shader};
+///
+/// type Declared = (((), Indexed<0, f32>), Indexed<1, [f32; 3]>);
+///
+/// let shader: Shader<Declared> = Builder::<Declared, ()>::new()
+///     .set(Indexed::<1, [f32; 3]>::new([1.0; 3]))
+///     .set(Indexed::<0, f32>::new(0.5))
+///     .build();
+/// ```
+///
+/// ```compile_fail
+/// use hlist::indexed::Indexed;
+/// use hlist::shader::Builder;
+///
+/// type Declared = (((), Indexed<0, f32>), Indexed<1, [f32; 3]>);
+///
+/// let shader = Builder::<Declared, ()>::new()
+///     .set(Indexed::<1, [f32; 3]>::new([1.0; 3]))
+///     .build();
+/// ```
+pub struct Builder<UUS, IUS, DUS = UUS>(UnInitUniforms<UUS>, InitUniforms<IUS>, PhantomData<DUS>);
+
+impl<UUS: lhlist::Base> Builder<UUS, (), UUS> {
+    pub fn new() -> Self {
+        Self(UnInitUniforms(PhantomData), InitUniforms(()), PhantomData)
+    }
+}
+
+impl<UUS: lhlist::Base> Default for Builder<UUS, (), UUS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<UUS: lhlist::Base, IUS: lhlist::Base, DUS: lhlist::Base> Builder<UUS, IUS, DUS> {
+    /// Set declared uniform, uniform is located by its type.
+    ///
+    /// Setting uniform which was not declared or was already set fails compilation.
+    #[allow(clippy::type_complexity)]
+    pub fn set<U, I>(self, value: U) -> Builder<<UUS as lhlist::Remover<U, I>>::Remainder, (IUS, U), DUS>
+    where
+        UUS: lhlist::Remover<U, I>,
+        I: counters::Index,
+    {
+        let Self(_, InitUniforms(uniforms), _) = self;
+        Builder(UnInitUniforms(PhantomData), InitUniforms((uniforms, value)), PhantomData)
+    }
+}
+
+impl<IUS: lhlist::Base, DUS: lhlist::Base> Builder<(), IUS, DUS> {
+    pub fn build<Is>(self) -> Shader<DUS>
+    where
+        IUS: Arrange<DUS, Is>,
+    {
+        let Self(_, InitUniforms(uniforms), _) = self;
+        Shader { uniforms: uniforms.arrange() }
+    }
+}
+
+/// Reordering of LHList into `Order`, elements are located by their type.
+pub trait Arrange<Order: lhlist::Base, Is>: lhlist::Base {
+    fn arrange(self) -> Order;
+}
+
+/// Base case
+impl Arrange<(), ()> for () {
+    fn arrange(self) { }
+}
+
+/// Inductive step
+impl<L, H, E, I, Is> Arrange<(H, E), (I, Is)> for L
+where
+    L: lhlist::Remover<E, I>,
+    L::Remainder: Arrange<H, Is>,
+    H: lhlist::Base,
+    I: counters::Index,
+{
+    fn arrange(self) -> (H, E) {
+        let (elem, rest) = self.remove();
+        (rest.arrange(), elem)
+    }
+}
+
+
+// --------==========[ Program ]==========--------
+
+/// Linked shader stages with merged uniforms.
+pub struct Program<US> {
+    uniforms: US,
+}
+
+impl<US: indexed::lhlist::Base> Program<US> {
+    /// Program of single stage.
+    pub fn new(stage: Shader<US>) -> Self {
+        Self { uniforms: stage.uniforms }
+    }
+
+    /// Link another stage into Program.
+    pub fn attach<SS, Is>(self, stage: Shader<SS>) -> Program<US::Output>
+    where
+        US: indexed::lhlist::Merge<SS, Is>,
+        SS: lhlist::Base,
+    {
+        Program { uniforms: self.uniforms.merge(stage.into_uniforms()) }
+    }
+
+    pub fn uniforms(&self) -> &US {
+        &self.uniforms
+    }
+
+    pub fn uniforms_mut(&mut self) -> &mut US {
+        &mut self.uniforms
+    }
+
+    pub fn into_uniforms(self) -> US {
+        self.uniforms
+    }
+}
@@ -1,34 +1,5 @@
 #![allow(unused)]
 
-use std::marker::PhantomData;
-
-use crate::indexed::lhlist;
-
-
-pub struct Entry<const INDEX: usize, U>(PhantomData<U>);
-
-#[derive(Default)]
-pub struct Registry<US = (), INDS = ()> {
-    uniforms: US,
-    indices: INDS
-}
-
-impl<US> Registry<US, ()> {
-    pub fn add<const INDEX: usize, U>(self, value: U) -> Registry<(US, U), ()> {
-        let Self { uniforms, indices } = self;
-        Registry {
-            uniforms: (uniforms, value), 
-            indices
-        }
-    }
-}
-
-impl<US: , INDS: lhlist::Base> Registry<US, INDS> {
-    pub fn get<const INDEX: usize>(&self) {
-        // self.uniforms.get()
-    }
-}
-
 
 pub fn hlist() -> HList {
     (
@@ -43,10 +14,6 @@ pub fn hlist() -> HList {
     )  
 }
 
-pub struct Shader<US>(PhantomData<US>);
-
-pub struct Builder<UUS, IUS>(UnInitUniforms<UUS>, InitUniforms<IUS>);
-
 type HList = (
     ((((), i32), u32), f32),
     (
@@ -57,12 +24,3 @@ type HList = (
         )
     ),
 );
-
-pub struct UnInitUniforms<US>(US);
-pub struct InitUniforms<US>(US);
-
-impl Builder<(), ()> {
-    pub fn new() -> Self {
-        Self(UnInitUniforms(()), InitUniforms(()))
-    }
-}
@@ -0,0 +1,213 @@
+use super::codegen::Labelled;
+use super::indexed::{lhlist, Indexed};
+use super::shader::{Program, Shader};
+
+
+// --------==========[ Backend ]==========--------
+
+/// Single scalar uniform value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Scalar {
+    Bool(bool),
+    I32(i32),
+    U32(u32),
+    F32(f32),
+}
+
+/// Graphics API setting uniform values at their locations.
+pub trait UniformBackend {
+    fn set_scalar(&mut self, location: usize, value: Scalar);
+
+    /// Vector of 2 to 4 components
+    fn set_vector(&mut self, location: usize, value: &[Scalar]);
+
+    /// Matrix of `columns` x `rows` floats stored column by column
+    fn set_matrix(&mut self, location: usize, columns: usize, rows: usize, value: &[f32]);
+}
+
+
+// --------==========[ Uniform Values ]==========--------
+
+/// Value which can be uploaded as a single uniform.
+pub trait Uniform {
+    fn upload<B: UniformBackend + ?Sized>(&self, location: usize, backend: &mut B);
+}
+
+macro_rules! impl_scalar_uniform {
+    ($($ty:ty => $variant:ident),*) => {
+        $(
+            impl Uniform for $ty {
+                fn upload<B: UniformBackend + ?Sized>(&self, location: usize, backend: &mut B) {
+                    backend.set_scalar(location, Scalar::$variant(*self));
+                }
+            }
+
+            impl_scalar_uniform!(@vector $ty => $variant; 2 3 4);
+        )*
+    };
+    (@vector $ty:ty => $variant:ident; $($n:literal)*) => {
+        $(
+            impl Uniform for [$ty; $n] {
+                fn upload<B: UniformBackend + ?Sized>(&self, location: usize, backend: &mut B) {
+                    backend.set_vector(location, &self.map(Scalar::$variant));
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_uniform!(bool => Bool, i32 => I32, u32 => U32, f32 => F32);
+
+macro_rules! impl_matrix_uniform {
+    ($(($columns:literal, $rows:literal))*) => {
+        $(
+            /// Matrix as array of columns
+            impl Uniform for [[f32; $rows]; $columns] {
+                fn upload<B: UniformBackend + ?Sized>(&self, location: usize, backend: &mut B) {
+                    backend.set_matrix(location, $columns, $rows, self.as_flattened());
+                }
+            }
+        )*
+    };
+}
+
+impl_matrix_uniform!((2, 2) (2, 3) (2, 4) (3, 2) (3, 3) (3, 4) (4, 2) (4, 3) (4, 4));
+
+impl<T: Uniform> Uniform for Labelled<T> {
+    fn upload<B: UniformBackend + ?Sized>(&self, location: usize, backend: &mut B) {
+        self.value.upload(location, backend)
+    }
+}
+
+
+// --------==========[ Upload ]==========--------
+
+/// Uploading every element of indexed LHList at location given by its `INDEX`
+pub trait Upload: lhlist::Base {
+    fn upload<B: UniformBackend + ?Sized>(&self, backend: &mut B);
+}
+
+/// Base case
+impl Upload for () {
+    fn upload<B: UniformBackend + ?Sized>(&self, _: &mut B) { }
+}
+
+/// Inductive step
+impl<const INDEX: usize, H: Upload, U: Uniform> Upload for (H, Indexed<INDEX, U>) {
+    fn upload<B: UniformBackend + ?Sized>(&self, backend: &mut B) {
+        self.0.upload(backend);
+        self.1.get().upload(INDEX, backend);
+    }
+}
+
+impl<US: Upload> Shader<US> {
+    /// Upload all uniforms, one backend call per uniform.
+    pub fn upload<B: UniformBackend + ?Sized>(&self, backend: &mut B) {
+        self.uniforms().upload(backend)
+    }
+}
+
+impl<US: Upload> Program<US> {
+    /// Upload all uniforms of all stages, shared uniforms only once.
+    pub fn upload<B: UniformBackend + ?Sized>(&self, backend: &mut B) {
+        self.uniforms().upload(backend)
+    }
+}
+
+
+// --------==========[ Recording Backend ]==========--------
+
+/// Backend call recorded by `RecordingBackend`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Call {
+    Scalar { location: usize, value: Scalar },
+    Vector { location: usize, value: Vec<Scalar> },
+    Matrix { location: usize, columns: usize, rows: usize, value: Vec<f32> },
+}
+
+/// In-memory backend recording calls instead of talking to GPU.
+#[derive(Clone, Debug, Default)]
+pub struct RecordingBackend {
+    pub calls: Vec<Call>,
+}
+
+impl RecordingBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl UniformBackend for RecordingBackend {
+    fn set_scalar(&mut self, location: usize, value: Scalar) {
+        self.calls.push(Call::Scalar { location, value });
+    }
+
+    fn set_vector(&mut self, location: usize, value: &[Scalar]) {
+        self.calls.push(Call::Vector { location, value: value.to_vec() });
+    }
+
+    fn set_matrix(&mut self, location: usize, columns: usize, rows: usize, value: &[f32]) {
+        self.calls.push(Call::Matrix { location, columns, rows, value: value.to_vec() });
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shader::{Builder, Program};
+
+    #[test]
+    fn shader_upload_records_one_call_per_uniform() {
+        type Declared = (((((), Indexed<4, [[f32; 2]; 2]>), Indexed<0, f32>), Indexed<1, [i32; 3]>), Indexed<7, bool>);
+
+        let shader = Builder::<Declared, ()>::new()
+            .set(Indexed::<0, f32>::new(0.5))
+            .set(Indexed::<1, [i32; 3]>::new([1, 2, 3]))
+            .set(Indexed::<4, [[f32; 2]; 2]>::new([[1.0, 2.0], [3.0, 4.0]]))
+            .set(Indexed::<7, bool>::new(true))
+            .build();
+
+        let mut backend = RecordingBackend::new();
+        shader.upload(&mut backend);
+
+        // Uniforms are uploaded in declared order
+        assert_eq!(backend.calls, [
+            Call::Matrix { location: 4, columns: 2, rows: 2, value: vec![1.0, 2.0, 3.0, 4.0] },
+            Call::Scalar { location: 0, value: Scalar::F32(0.5) },
+            Call::Vector { location: 1, value: vec![Scalar::I32(1), Scalar::I32(2), Scalar::I32(3)] },
+            Call::Scalar { location: 7, value: Scalar::Bool(true) },
+        ]);
+    }
+
+    #[test]
+    fn program_upload_keeps_shared_uniforms_once() {
+        type Vertex = (((), Indexed<0, [[f32; 4]; 4]>), Indexed<1, f32>);
+        type Fragment = (((), Indexed<1, f32>), Indexed<2, [f32; 3]>);
+        type Compute = ((), Indexed<300, u32>);
+
+        let vertex = Builder::<Vertex, ()>::new()
+            .set(Indexed::<0, [[f32; 4]; 4]>::new([[1.0; 4]; 4]))
+            .set(Indexed::<1, f32>::new(0.5))
+            .build();
+        let fragment = Builder::<Fragment, ()>::new()
+            .set(Indexed::<1, f32>::new(0.25))
+            .set(Indexed::<2, [f32; 3]>::new([1.0, 0.0, 0.0]))
+            .build();
+        let other = Builder::<Compute, ()>::new()
+            .set(Indexed::<300, u32>::new(9))
+            .build();
+
+        let program: Program<_> = vertex.link(fragment).attach(other);
+        let mut backend = RecordingBackend::new();
+        program.upload(&mut backend);
+
+        // Shared uniform keeps value of the first stage
+        assert_eq!(backend.calls, [
+            Call::Matrix { location: 0, columns: 4, rows: 4, value: vec![1.0; 16] },
+            Call::Scalar { location: 1, value: Scalar::F32(0.5) },
+            Call::Vector { location: 2, value: vec![Scalar::F32(1.0), Scalar::F32(0.0), Scalar::F32(0.0)] },
+            Call::Scalar { location: 300, value: Scalar::U32(9) },
+        ]);
+    }
+}
@@ -0,0 +1,360 @@
+use std::error::Error;
+use std::fmt;
+
+use super::codegen::Declarations;
+use super::shader::Shader;
+
+
+// --------==========[ GLSL Parsing ]==========--------
+
+/// Uniform declared in GLSL source
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GlslUniform {
+    pub name: String,
+    pub ty: String,
+    pub location: Option<usize>,
+    /// Length of array uniform, `Some(0)` when it is not a literal
+    pub array: Option<usize>,
+}
+
+/// Remove comments and preprocessor directives
+fn strip(source: &str) -> String {
+    let mut stripped = String::with_capacity(source.len());
+    let mut rest = source;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("//") {
+            rest = comment.find('\n').map_or("", |end| &comment[end..]);
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.find("*/").map_or("", |end| &comment[end + 2..]);
+            stripped.push(' ');
+        } else {
+            let c = rest.chars().next().unwrap();
+            stripped.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    stripped
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Split source into identifiers, numbers and single punctuation characters
+fn tokenize(source: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in source.char_indices() {
+        if c.is_alphanumeric() || c == '_' || c == '.' {
+            start.get_or_insert(i);
+            continue;
+        }
+        if let Some(start) = start.take() {
+            tokens.push(&source[start..i]);
+        }
+        if !c.is_whitespace() {
+            tokens.push(&source[i..i + c.len_utf8()]);
+        }
+    }
+    if let Some(start) = start {
+        tokens.push(&source[start..]);
+    }
+    tokens
+}
+
+/// Statements outside of any braces, i.e. global declarations
+///
+/// Braced bodies of functions, structs and uniform blocks are skipped
+/// together with whatever preceded them in the same statement.
+fn global_statements<'a>(tokens: &[&'a str]) -> Vec<Vec<&'a str>> {
+    let mut statements = Vec::new();
+    let mut statement = Vec::new();
+    let mut depth = 0usize;
+    for &token in tokens {
+        match token {
+            "{" => {
+                depth += 1;
+                statement.clear();
+            }
+            "}" => depth = depth.saturating_sub(1),
+            ";" if depth == 0 => statements.push(std::mem::take(&mut statement)),
+            _ if depth == 0 => statement.push(token),
+            _ => { }
+        }
+    }
+    statements
+}
+
+/// Location given by `layout(...)` qualifiers, if any
+fn parse_layout(qualifiers: &[&str]) -> Option<usize> {
+    qualifiers.windows(3).find_map(|qualifier| match qualifier {
+        ["location", "=", value] => value.parse().ok(),
+        _ => None,
+    })
+}
+
+/// Square matrices have two names, the short one is used by `codegen`
+fn normalize_type(ty: &str) -> &str {
+    match ty {
+        "mat2x2" => "mat2",
+        "mat3x3" => "mat3",
+        "mat4x4" => "mat4",
+        _ => ty,
+    }
+}
+
+fn parse_statement(statement: &[&str], uniforms: &mut Vec<GlslUniform>) {
+    let mut tokens = statement.iter().copied().peekable();
+
+    let mut location = None;
+    if tokens.next_if_eq(&"layout").is_some() {
+        if tokens.next() != Some("(") {
+            return;
+        }
+        let qualifiers: Vec<_> = tokens.by_ref().take_while(|&token| token != ")").collect();
+        location = parse_layout(&qualifiers);
+    }
+
+    let mut tokens = tokens.filter(|token| !matches!(*token, "highp" | "mediump" | "lowp"));
+    if tokens.next() != Some("uniform") {
+        return;
+    }
+    let Some(ty) = tokens.next() else {
+        return;
+    };
+    let ty = normalize_type(ty);
+
+    // Declarators separated by commas outside of initializer parentheses
+    let mut declarators = vec![Vec::new()];
+    let mut depth = 0usize;
+    for token in tokens {
+        match token {
+            "(" => depth += 1,
+            ")" => depth = depth.saturating_sub(1),
+            "," if depth == 0 => declarators.push(Vec::new()),
+            _ => { }
+        }
+        declarators.last_mut().unwrap().push(token);
+    }
+
+    for declarator in declarators {
+        let declarator = declarator.strip_prefix(&[","]).unwrap_or(&declarator);
+        let [name, rest @ ..] = declarator else {
+            continue;
+        };
+        let array = match rest {
+            ["[", length, "]", ..] => Some(length.parse().unwrap_or(0)),
+            ["[", "]", ..] => Some(0),
+            _ => None,
+        };
+        uniforms.push(GlslUniform { name: (*name).into(), ty: ty.into(), location, array });
+    }
+}
+
+/// Collect plain `uniform` declarations of GLSL source, uniform blocks are skipped.
+pub fn parse_uniforms(source: &str) -> Vec<GlslUniform> {
+    let stripped = strip(source);
+    let mut uniforms = Vec::new();
+    for statement in global_statements(&tokenize(&stripped)) {
+        parse_statement(&statement, &mut uniforms);
+    }
+    uniforms
+}
+
+
+// --------==========[ Validation ]==========--------
+
+/// Difference between uniform list and GLSL source
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mismatch {
+    /// Uniform of the list is not declared in source
+    Missing { name: String, ty: String },
+    /// Uniform declared in source is not in the list
+    Extra { name: String, ty: String },
+    Type { name: String, expected: String, found: String },
+    Location { name: String, expected: Option<usize>, found: Option<usize> },
+    /// Array length differs, `None` stands for a single value
+    Array { name: String, expected: Option<usize>, found: Option<usize> },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = |location: &Option<usize>| match location {
+            Some(location) => location.to_string(),
+            None => "none".into(),
+        };
+        let array = |array: &Option<usize>| match array {
+            Some(length) => format!("array of {length}"),
+            None => "single value".into(),
+        };
+        match self {
+            Self::Missing { name, ty } => write!(f, "uniform `{ty} {name}` is not declared in source"),
+            Self::Extra { name, ty } => write!(f, "uniform `{ty} {name}` declared in source is not in the list"),
+            Self::Type { name, expected, found } => {
+                write!(f, "uniform `{name}` has type `{found}`, expected `{expected}`")
+            }
+            Self::Location { name, expected, found } => write!(
+                f,
+                "uniform `{name}` has location {}, expected {}",
+                location(found),
+                location(expected),
+            ),
+            Self::Array { name, expected, found } => write!(
+                f,
+                "uniform `{name}` is {}, expected {}",
+                array(found),
+                array(expected),
+            ),
+        }
+    }
+}
+
+/// All mismatches found by validation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} uniform mismatch(es)", self.mismatches.len())?;
+        for mismatch in &self.mismatches {
+            write!(f, "\n  {mismatch}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ValidationError { }
+
+/// Compare uniform list with uniforms declared in GLSL source, matching them by name.
+pub fn validate<US: Declarations>(uniforms: &US, source: &str) -> Result<(), ValidationError> {
+    let declared = uniforms.declarations();
+    let mut parsed = parse_uniforms(source);
+    let mut mismatches = Vec::new();
+
+    for declaration in declared {
+        let Some(position) = parsed.iter().position(|uniform| uniform.name == declaration.name) else {
+            mismatches.push(Mismatch::Missing {
+                name: declaration.name.into(),
+                ty: declaration.glsl.into(),
+            });
+            continue;
+        };
+        let uniform = parsed.remove(position);
+        if uniform.ty != declaration.glsl {
+            mismatches.push(Mismatch::Type {
+                name: uniform.name.clone(),
+                expected: declaration.glsl.into(),
+                found: uniform.ty,
+            });
+        }
+        if uniform.location != declaration.location {
+            mismatches.push(Mismatch::Location {
+                name: uniform.name.clone(),
+                expected: declaration.location,
+                found: uniform.location,
+            });
+        }
+        // Uniform lists hold single values only
+        if uniform.array.is_some() {
+            mismatches.push(Mismatch::Array {
+                name: uniform.name,
+                expected: None,
+                found: uniform.array,
+            });
+        }
+    }
+
+    mismatches.extend(parsed.into_iter().map(|GlslUniform { name, ty, .. }| Mismatch::Extra { name, ty }));
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError { mismatches })
+    }
+}
+
+impl<US: Declarations> Shader<US> {
+    /// Check uniforms of Shader against GLSL source.
+    pub fn validate(&self, source: &str) -> Result<(), ValidationError> {
+        validate(self.uniforms(), source)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::Labelled;
+    use crate::indexed::Indexed;
+
+    fn uniform(name: &str, ty: &str, location: Option<usize>, array: Option<usize>) -> GlslUniform {
+        GlslUniform { name: name.into(), ty: ty.into(), location, array }
+    }
+
+    #[test]
+    fn uniforms_after_functions() {
+        let source = "uniform mat4 mvp; void helper() { return; } uniform float late; void main() { }";
+        assert_eq!(parse_uniforms(source), [
+            uniform("mvp", "mat4", None, None),
+            uniform("late", "float", None, None),
+        ]);
+    }
+
+    #[test]
+    fn comments_directives_and_blocks() {
+        let source = "
+            #version 430
+            // uniform float commented;
+            /* uniform float blocked; */
+            uniform Block { float member; } block;
+            struct Light { vec3 color; };
+            layout(std140, location = 3) uniform highp vec3 color;
+        ";
+        assert_eq!(parse_uniforms(source), [uniform("color", "vec3", Some(3), None)]);
+    }
+
+    #[test]
+    fn declarators_arrays_and_initializers() {
+        let source = "uniform vec3 a = vec3(1.0, 2.0, 3.0), b[4], c[]; uniform mat4x4 m;";
+        assert_eq!(parse_uniforms(source), [
+            uniform("a", "vec3", None, None),
+            uniform("b", "vec3", None, Some(4)),
+            uniform("c", "vec3", None, Some(0)),
+            uniform("m", "mat4", None, None),
+        ]);
+    }
+
+    #[test]
+    fn validation_mismatches() {
+        let uniforms = (
+            (((), Indexed::<0, _>::new(Labelled::new("time", 0.0f32))), Labelled::new("color", [0.0f32; 3])),
+            Indexed::<2, _>::new(Labelled::new("mvp", [[0.0f32; 4]; 4])),
+        );
+        assert_eq!(validate(&uniforms, "
+            layout(location = 0) uniform float time;
+            uniform vec3 color;
+            layout(location = 2) uniform mat4x4 mvp;
+        "), Ok(()));
+
+        let error = validate(&uniforms, "
+            layout(location = 1) uniform float time[2];
+            uniform vec4 color;
+            uniform sampler2D tex;
+        ").unwrap_err();
+        assert_eq!(error.mismatches, [
+            Mismatch::Location { name: "time".into(), expected: Some(0), found: Some(1) },
+            Mismatch::Array { name: "time".into(), expected: None, found: Some(2) },
+            Mismatch::Type { name: "color".into(), expected: "vec3".into(), found: "vec4".into() },
+            Mismatch::Missing { name: "mvp".into(), ty: "mat4".into() },
+            Mismatch::Extra { name: "tex".into(), ty: "sampler2D".into() },
+        ]);
+        assert_eq!(error.to_string(), "5 uniform mismatch(es)
+  uniform `time` has location 1, expected 0
+  uniform `time` is array of 2, expected single value
+  uniform `color` has type `vec4`, expected `vec3`
+  uniform `mat4 mvp` is not declared in source
+  uniform `sampler2D tex` declared in source is not in the list");
+    }
+}
@@ -0,0 +1,240 @@
+use super::indexed::Indexed;
+use super::lhlist;
+
+
+// --------==========[ Vertex Formats ]==========--------
+
+/// Format of single vertex attribute
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VertexFormat {
+    Uint32,
+    Uint32x2,
+    Uint32x3,
+    Uint32x4,
+    Sint32,
+    Sint32x2,
+    Sint32x3,
+    Sint32x4,
+    Float32,
+    Float32x2,
+    Float32x3,
+    Float32x4,
+}
+
+impl VertexFormat {
+    pub const fn components(self) -> usize {
+        match self {
+            Self::Uint32 | Self::Sint32 | Self::Float32 => 1,
+            Self::Uint32x2 | Self::Sint32x2 | Self::Float32x2 => 2,
+            Self::Uint32x3 | Self::Sint32x3 | Self::Float32x3 => 3,
+            Self::Uint32x4 | Self::Sint32x4 | Self::Float32x4 => 4,
+        }
+    }
+
+    /// Size in bytes
+    pub const fn size(self) -> usize {
+        4 * self.components()
+    }
+}
+
+
+// --------==========[ Attributes ]==========--------
+
+/// Value usable as vertex attribute.
+pub trait Attribute {
+    const FORMAT: VertexFormat;
+    /// Explicit shader location, lowest location unused by other attributes is taken when missing
+    const LOCATION: Option<usize> = None;
+
+    /// Write value into `buf` of exactly `FORMAT.size()` bytes
+    fn write(&self, buf: &mut [u8]);
+}
+
+macro_rules! impl_attribute {
+    ($($ty:ty => [$scalar:ident, $x2:ident, $x3:ident, $x4:ident]),*) => {
+        $(
+            impl Attribute for $ty {
+                const FORMAT: VertexFormat = VertexFormat::$scalar;
+
+                fn write(&self, buf: &mut [u8]) {
+                    buf.copy_from_slice(&self.to_le_bytes());
+                }
+            }
+
+            impl_attribute!(@vector $ty; (2, $x2) (3, $x3) (4, $x4));
+        )*
+    };
+    (@vector $ty:ty; $(($n:literal, $format:ident))*) => {
+        $(
+            impl Attribute for [$ty; $n] {
+                const FORMAT: VertexFormat = VertexFormat::$format;
+
+                fn write(&self, buf: &mut [u8]) {
+                    for (value, buf) in self.iter().zip(buf.chunks_exact_mut(4)) {
+                        value.write(buf);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_attribute!(
+    u32 => [Uint32, Uint32x2, Uint32x3, Uint32x4],
+    i32 => [Sint32, Sint32x2, Sint32x3, Sint32x4],
+    f32 => [Float32, Float32x2, Float32x3, Float32x4]
+);
+
+impl<const INDEX: usize, T: Attribute> Attribute for Indexed<INDEX, T> {
+    const FORMAT: VertexFormat = T::FORMAT;
+    const LOCATION: Option<usize> = Some(INDEX);
+
+    fn write(&self, buf: &mut [u8]) {
+        self.get().write(buf)
+    }
+}
+
+
+// --------==========[ Vertex Layout ]==========--------
+
+/// Placement of single attribute in a vertex
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VertexAttribute {
+    pub format: VertexFormat,
+    pub offset: usize,
+    pub location: usize,
+}
+
+/// Description of tightly packed vertex buffer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VertexLayout {
+    pub stride: usize,
+    pub attributes: Vec<VertexAttribute>,
+}
+
+/// LHList of attributes forming single vertex
+pub trait Vertex: lhlist::Base {
+    const STRIDE: usize;
+
+    fn explicit_locations(locations: &mut Vec<usize>);
+
+    /// Push attributes, implicit locations skip `explicit` ones and those already taken
+    fn collect_attributes(attributes: &mut Vec<VertexAttribute>, explicit: &[usize]);
+
+    /// Write attributes into `buf` of exactly `STRIDE` bytes
+    fn write_vertex(&self, buf: &mut [u8]);
+
+    fn layout() -> VertexLayout {
+        let mut explicit = Vec::new();
+        Self::explicit_locations(&mut explicit);
+        let mut attributes = Vec::with_capacity(Self::LENGTH);
+        Self::collect_attributes(&mut attributes, &explicit);
+        VertexLayout { stride: Self::STRIDE, attributes }
+    }
+}
+
+/// Base case
+impl Vertex for () {
+    const STRIDE: usize = 0;
+
+    fn explicit_locations(_: &mut Vec<usize>) { }
+
+    fn collect_attributes(_: &mut Vec<VertexAttribute>, _: &[usize]) { }
+
+    fn write_vertex(&self, _: &mut [u8]) { }
+}
+
+/// Inductive step
+impl<H: Vertex, E: Attribute> Vertex for (H, E) {
+    const STRIDE: usize = H::STRIDE + E::FORMAT.size();
+
+    fn explicit_locations(locations: &mut Vec<usize>) {
+        H::explicit_locations(locations);
+        locations.extend(E::LOCATION);
+    }
+
+    fn collect_attributes(attributes: &mut Vec<VertexAttribute>, explicit: &[usize]) {
+        H::collect_attributes(attributes, explicit);
+        let location = E::LOCATION.unwrap_or_else(|| {
+            (0..)
+                .find(|location| {
+                    !explicit.contains(location) && attributes.iter().all(|attribute| attribute.location != *location)
+                })
+                .unwrap()
+        });
+        attributes.push(VertexAttribute { format: E::FORMAT, offset: H::STRIDE, location });
+    }
+
+    fn write_vertex(&self, buf: &mut [u8]) {
+        let (head, elem) = buf.split_at_mut(H::STRIDE);
+        self.0.write_vertex(head);
+        self.1.write(elem);
+    }
+}
+
+/// Interleave attributes of all vertices into single buffer
+pub fn interleave<V: Vertex>(vertices: &[V]) -> Vec<u8> {
+    let mut buf = vec![0; vertices.len() * V::STRIDE];
+    if V::STRIDE > 0 {
+        for (vertex, buf) in vertices.iter().zip(buf.chunks_exact_mut(V::STRIDE)) {
+            vertex.write_vertex(buf);
+        }
+    }
+    buf
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attribute(format: VertexFormat, offset: usize, location: usize) -> VertexAttribute {
+        VertexAttribute { format, offset, location }
+    }
+
+    #[test]
+    fn implicit_locations() {
+        type Plain = ((((), [f32; 3]), [f32; 2]), u32);
+
+        assert_eq!(Plain::layout(), VertexLayout {
+            stride: 24,
+            attributes: vec![
+                attribute(VertexFormat::Float32x3, 0, 0),
+                attribute(VertexFormat::Float32x2, 12, 1),
+                attribute(VertexFormat::Uint32, 20, 2),
+            ],
+        });
+        assert_eq!(<() as Vertex>::layout(), VertexLayout { stride: 0, attributes: vec![] });
+    }
+
+    #[test]
+    fn explicit_locations_are_skipped() {
+        type Mixed = (((((), Indexed<1, [f32; 3]>), [f32; 2]), Indexed<0, i32>), [u32; 4]);
+
+        assert_eq!(Mixed::layout(), VertexLayout {
+            stride: 40,
+            attributes: vec![
+                attribute(VertexFormat::Float32x3, 0, 1),
+                // locations 0 and 1 are taken by indexed attributes
+                attribute(VertexFormat::Float32x2, 12, 2),
+                attribute(VertexFormat::Sint32, 20, 0),
+                attribute(VertexFormat::Uint32x4, 24, 3),
+            ],
+        });
+    }
+
+    #[test]
+    fn interleaved_bytes() {
+        let vertices = [
+            (((), [1.0f32, 2.0]), Indexed::<3, _>::new(7u32)),
+            (((), [3.0f32, 4.0]), Indexed::<3, _>::new(8u32)),
+        ];
+        let bytes = interleave(&vertices);
+        let words: Vec<[u8; 4]> = bytes.chunks_exact(4).map(|chunk| chunk.try_into().unwrap()).collect();
+        assert_eq!(words, [
+            1.0f32.to_le_bytes(), 2.0f32.to_le_bytes(), 7u32.to_le_bytes(),
+            3.0f32.to_le_bytes(), 4.0f32.to_le_bytes(), 8u32.to_le_bytes(),
+        ]);
+        assert!(interleave::<()>(&[(), ()]).is_empty());
+    }
+}